@@ -0,0 +1,562 @@
+//! Written accents, or tildes, and the RAE rules that place them.
+
+use crate::char_util::{add_accent, strip_accent, IsVowel};
+use crate::reform::{is_hiatus_monosyllable, Orthography};
+use crate::str_util::stress_index;
+use crate::syllable::Syllable;
+use crate::{stress_type, StressType, Word};
+
+/// How the stress of a word is marked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccentType {
+    /// Acento ortográfico, the stressed vowel carries a tilde, "canción".
+    Orthographic,
+    /// Acento prosódico, the stress is only pronounced, "casa".
+    Prosodic,
+}
+
+/// What the RAE rules say about a tilde on the stressed vowel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tilde {
+    /// The word must be written with a tilde, "canción", "árbol", "país".
+    Required,
+    /// The word must be written without one, "casa", "reloj", "fue".
+    Forbidden,
+    /// The tilde tells apart words otherwise spelled the same, so it depends
+    /// on the meaning, "él" and "el", "qué" and "que".
+    Diacritic,
+    /// Either spelling is right, depending on pronunciation. Only under the
+    /// 1999 orthography, "guion" and "guión".
+    Optional,
+}
+
+/// Stress of a word, where it falls and how it's written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Accent {
+    pub accent_type: AccentType,
+    /// Index of the stressed syllable.
+    pub syllable: usize,
+    /// The stressed vowel, as written.
+    pub vowel: char,
+    /// Index of the stressed vowel among the characters of the word.
+    pub position: usize,
+    pub tilde: Tilde,
+}
+
+/// Ways a written accent can break the RAE rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TildeError {
+    /// The word needs a tilde it doesn't have, "camion" for "camión".
+    Missing,
+    /// The word has a tilde it shouldn't, "fué" for "fue", "exámen" for
+    /// "examen".
+    Superfluous,
+    /// The word has a tilde, but not only on the stressed vowel.
+    Misplaced,
+}
+
+/// Result of checking the written accent of a word.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TildeCheck {
+    /// What the rules say for the checked stress.
+    pub tilde: Tilde,
+    /// `None` if the word is spelled right, or if the tilde is diacritic and
+    /// either spelling could be right.
+    pub error: Option<TildeError>,
+    /// The word spelled with its tilde where the rules put it.
+    pub suggestion: String,
+}
+
+/// A word spelled without tildes, with the stress it had, so that it can be
+/// spelled back, e.g. for search indexes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrippedWord {
+    /// The word without tildes. The diaeresis is kept, "pingüino".
+    pub bare: String,
+    /// Index of the stressed syllable in the original word.
+    pub stress_index: usize,
+    /// Index of the stressed vowel among the characters of the word.
+    pub position: usize,
+    /// Whether the original word had a tilde, which matters for words with a
+    /// diacritic one, "él" and "el".
+    pub accent_type: AccentType,
+    /// Indices of the syllables in the original word that end a hiatus with
+    /// the next one, "pa-ís".
+    pub hiatuses: Vec<usize>,
+}
+
+impl StrippedWord {
+    /// Spells the word back with its tildes, or `None` if the stress data
+    /// doesn't fit the bare word.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syllabize_es::Word;
+    /// let stripped = Word::from("término").strip_accents().unwrap();
+    /// assert_eq!(stripped.bare, "termino");
+    /// assert_eq!(stripped.restore().unwrap(), "término");
+    /// ```
+    pub fn restore(&self) -> Option<String> {
+        let mut chars = self.bare.chars().collect::<Vec<char>>();
+        let vowel = chars.get_mut(self.position)?;
+        if is_diacritic(&self.bare) {
+            if self.accent_type == AccentType::Orthographic {
+                *vowel = add_accent(*vowel);
+            }
+            return Some(chars.into_iter().collect());
+        }
+        // A tilde on the stressed vowel keeps a hiatus apart while parsing,
+        // "pais" would be a single syllable.
+        *vowel = add_accent(*vowel);
+        restress_at(&chars.into_iter().collect::<String>(), self.position)
+    }
+}
+
+/// Strips the tildes of a word, keeping its stress, or `None` if there's no
+/// stressed vowel.
+pub(crate) fn strip(word: &Word) -> Option<StrippedWord> {
+    let accent = word.accent()?;
+    Some(StrippedWord {
+        bare: word.to_string().chars().map(strip_accent).collect(),
+        stress_index: word.stress_index,
+        position: accent.position,
+        accent_type: accent.accent_type,
+        hiatuses: word
+            .vowel_combos()
+            .hiatuses
+            .iter()
+            .map(|h| h.syllable_index)
+            .collect(),
+    })
+}
+
+/// Words told apart by a diacritic tilde, without and with it.
+pub const DIACRITIC_PAIRS: &[(&str, &str)] = &[
+    ("el", "él"),
+    ("tu", "tú"),
+    ("mi", "mí"),
+    ("te", "té"),
+    ("se", "sé"),
+    ("de", "dé"),
+    ("si", "sí"),
+    ("mas", "más"),
+    ("aun", "aún"),
+    ("que", "qué"),
+    ("quien", "quién"),
+    ("cual", "cuál"),
+    ("como", "cómo"),
+    ("donde", "dónde"),
+    ("adonde", "adónde"),
+    ("cuando", "cuándo"),
+    ("cuanto", "cuánto"),
+];
+
+/// Returns true if the word, ignoring case and tildes, is one of
+/// [`DIACRITIC_PAIRS`].
+///
+/// ```
+/// use syllabize_es::accent::is_diacritic;
+/// assert!(is_diacritic("Él"));
+/// assert!(is_diacritic("que"));
+/// assert!(!is_diacritic("fue"));
+/// ```
+pub fn is_diacritic(word: &str) -> bool {
+    let plain = word
+        .chars()
+        .map(strip_accent)
+        .flat_map(char::to_lowercase)
+        .collect::<String>();
+    DIACRITIC_PAIRS.iter().any(|(p, _)| *p == plain)
+}
+
+/// Finds the stressed vowel of a word and the tilde rule for it, or `None`
+/// if the word has no syllables or its stressed syllable has no vowel.
+pub(crate) fn accent(syllables: &[Syllable], stress_index: usize) -> Option<Accent> {
+    let stressed = syllables.get(stress_index)?;
+    let nucleus = stressed.nucleus.chars().collect::<Vec<char>>();
+    let index = stressed_vowel(&stressed.nucleus)?;
+    let vowel = nucleus[index];
+    let position = syllable_start(syllables, stress_index) + stressed.onset.chars().count() + index;
+    Some(Accent {
+        accent_type: if vowel.is_accented_vowel() {
+            AccentType::Orthographic
+        } else {
+            AccentType::Prosodic
+        },
+        syllable: stress_index,
+        vowel,
+        position,
+        tilde: tilde_rule(syllables, stress_index),
+    })
+}
+
+/// Checks the written accent of a word stressed on `stress_index`, or `None`
+/// if there's no such syllable.
+pub(crate) fn check_tilde(
+    syllables: &[Syllable],
+    stress_index: usize,
+    orthography: Orthography,
+) -> Option<TildeCheck> {
+    let stressed = syllables.get(stress_index)?;
+    let written = syllables.iter().map(|s| s.to_string()).collect::<String>();
+    let tilde = if orthography == Orthography::Rae1999 && is_hiatus_monosyllable(&written) {
+        Tilde::Optional
+    } else {
+        tilde_rule(syllables, stress_index)
+    };
+    if matches!(tilde, Tilde::Diacritic | Tilde::Optional) {
+        return Some(TildeCheck {
+            tilde,
+            error: None,
+            suggestion: written,
+        });
+    }
+    let suggestion = spell(
+        syllables,
+        (tilde == Tilde::Required).then_some(stress_index),
+    );
+    let has_tilde = written.chars().any(|c| c.is_accented_vowel());
+    let error = if suggestion == written {
+        None
+    } else if !has_tilde {
+        Some(TildeError::Missing)
+    } else if tilde == Tilde::Forbidden || stressed_vowel(&stressed.nucleus).is_none() {
+        Some(TildeError::Superfluous)
+    } else {
+        Some(TildeError::Misplaced)
+    };
+    Some(TildeCheck {
+        tilde,
+        error,
+        suggestion,
+    })
+}
+
+/// Spells a word so that it's stressed on the syllable at `stress_index`,
+/// or `None` if there's no such syllable.
+///
+/// Adding or removing a tilde on a weak vowel may join or split syllables,
+/// "continúo" becomes "continuo", so the spelling is parsed again until the
+/// stress stays on the same vowel.
+pub(crate) fn restress(syllables: &[Syllable], stress_index: usize) -> Option<String> {
+    let stressed = syllables.get(stress_index)?;
+    let written = syllables.iter().map(|s| s.to_string()).collect::<String>();
+    if tilde_rule(syllables, stress_index) == Tilde::Diacritic {
+        return Some(written);
+    }
+    // Tildes don't change the length of the word, so the vowel stays put.
+    let position = syllable_start(syllables, stress_index)
+        + stressed.onset.chars().count()
+        + stressed_vowel(&stressed.nucleus).unwrap_or(0);
+
+    let mut spelling = written;
+    let mut syllables = syllables.to_vec();
+    let mut index = stress_index;
+    for _ in 0..4 {
+        let tilde_on = (tilde_rule(&syllables, index) == Tilde::Required).then_some(index);
+        let respelled = spell(&syllables, tilde_on);
+        if respelled == spelling {
+            break;
+        }
+        spelling = respelled;
+        syllables = Word::from(spelling.as_str()).syllables;
+        index = (0..syllables.len())
+            .rfind(|&i| syllable_start(&syllables, i) <= position)
+            .unwrap_or(0);
+    }
+    Some(spelling)
+}
+
+/// Spells a word so that the vowel at character `position` is stressed, or
+/// `None` if the word has no syllable there.
+pub(crate) fn restress_at(word: &str, position: usize) -> Option<String> {
+    let word = Word::from(word);
+    let index =
+        (0..word.syllables.len()).rfind(|&i| syllable_start(&word.syllables, i) <= position)?;
+    restress(&word.syllables, index)
+}
+
+/// Index of the first character of the syllable at `index`.
+fn syllable_start(syllables: &[Syllable], index: usize) -> usize {
+    syllables[..index]
+        .iter()
+        .map(|s| s.to_string().chars().count())
+        .sum()
+}
+
+/// Spells out syllables without tildes, except on the stressed vowel of the
+/// syllable at `tilde_on`, if any.
+pub(crate) fn spell(syllables: &[Syllable], tilde_on: Option<usize>) -> String {
+    let mut word = String::new();
+    for (i, syllable) in syllables.iter().enumerate() {
+        word.push_str(&syllable.onset);
+        let stressed = if tilde_on == Some(i) {
+            stressed_vowel(&syllable.nucleus)
+        } else {
+            None
+        };
+        for (j, c) in syllable.nucleus.chars().enumerate() {
+            let c = strip_accent(c);
+            word.push(if stressed == Some(j) {
+                add_accent(c)
+            } else {
+                c
+            });
+        }
+        word.push_str(&syllable.coda);
+    }
+    word
+}
+
+/// Index of the stressed vowel in a nucleus, skipping a final 'y', which is
+/// never stressed, "muy".
+pub(crate) fn stressed_vowel(nucleus: &str) -> Option<usize> {
+    let chars = nucleus.chars().collect::<Vec<char>>();
+    let index = stress_index(nucleus);
+    match chars.get(index)? {
+        'y' | 'Y' if index > 0 => Some(index - 1),
+        _ => Some(index),
+    }
+}
+
+/// Tilde rule for a word stressed on `stress_index`, whether it's written
+/// with a tilde or not.
+pub(crate) fn tilde_rule(syllables: &[Syllable], stress_index: usize) -> Tilde {
+    let word = syllables.iter().map(|s| s.to_string()).collect::<String>();
+    if is_diacritic(&word) {
+        return Tilde::Diacritic;
+    }
+    if syllables.len() < 2 {
+        return Tilde::Forbidden;
+    }
+    if is_stressed_hiatus(syllables, stress_index) {
+        return Tilde::Required;
+    }
+    let ends_like_vowel = ends_in_vowel_n_or_s(&word);
+    match stress_type(syllables.len(), stress_index) {
+        Some(StressType::Oxytone) if ends_like_vowel => Tilde::Required,
+        Some(StressType::Paroxytone) if !ends_like_vowel => Tilde::Required,
+        Some(StressType::Proparoxytone | StressType::Superproparoxytone) => Tilde::Required,
+        _ => Tilde::Forbidden,
+    }
+}
+
+/// Returns true if the word ends in a vowel, or in 'n' or 's' after a vowel.
+///
+/// 'y' after a vowel counts as a consonant, "virrey", but after a
+/// consonant it's a vowel, "curry", as for
+/// [`unaccented_stress`](crate::unaccented_stress). 'n' and 's' after
+/// another consonant count as consonants too, "robots", "bíceps".
+pub(crate) fn ends_in_vowel_n_or_s(word: &str) -> bool {
+    let chars = word
+        .chars()
+        .rev()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .collect::<Vec<char>>();
+    let is_vowel = |i: usize| {
+        chars.get(i).is_some_and(|&c| {
+            c.is_vowel() || (c == 'y' && chars.get(i + 1).is_some_and(|p| !p.is_vowel()))
+        })
+    };
+    match chars.first() {
+        Some(_) if is_vowel(0) => true,
+        Some('n' | 's') => is_vowel(1),
+        _ => false,
+    }
+}
+
+/// Returns true if the stress falls on a weak vowel next to a strong one,
+/// breaking what would be a diphthong, "país", "reúne", "búho".
+pub(crate) fn is_stressed_hiatus(syllables: &[Syllable], stress_index: usize) -> bool {
+    let Some(stressed) = syllables.get(stress_index) else {
+        return false;
+    };
+    let nucleus = stressed.nucleus.chars().collect::<Vec<char>>();
+    let Some(index) = stressed_vowel(&stressed.nucleus) else {
+        return false;
+    };
+    if !matches!(strip_accent(nucleus[index]), 'i' | 'u' | 'I' | 'U') {
+        return false;
+    }
+    // In "construía" the 'í' closes the diphthong "uí" and opens a hiatus.
+    let (first, last) = (index == 0, index + 1 == nucleus.len());
+    let opens_with_vowel = |s: &Syllable| s.onset.is_empty() || s.onset.eq_ignore_ascii_case("h");
+    let is_strong = |c: Option<char>| c.is_some_and(|c| c.is_vowel() && !c.is_weak_vowel());
+    let after_strong = stress_index
+        .checked_sub(1)
+        .and_then(|i| syllables.get(i))
+        .is_some_and(|prev| {
+            first
+                && prev.coda.is_empty()
+                && opens_with_vowel(stressed)
+                && is_strong(prev.nucleus.chars().last())
+        });
+    let before_strong = syllables.get(stress_index + 1).is_some_and(|next| {
+        last && stressed.coda.is_empty()
+            && opens_with_vowel(next)
+            && is_strong(next.nucleus.chars().next())
+    });
+    after_strong || before_strong
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Word;
+
+    fn tilde(s: &str) -> Tilde {
+        Word::from(s).accent().unwrap().tilde
+    }
+
+    #[test]
+    fn test_accent() {
+        let accent = Word::from("canción").accent().unwrap();
+        assert_eq!(accent.accent_type, AccentType::Orthographic);
+        assert_eq!(accent.syllable, 1);
+        assert_eq!(accent.vowel, 'ó');
+        assert_eq!(accent.position, 5);
+
+        let accent = Word::from("muy").accent().unwrap();
+        assert_eq!(accent.accent_type, AccentType::Prosodic);
+        assert_eq!(accent.vowel, 'u');
+        assert_eq!(Word::from("cuida").accent().unwrap().vowel, 'i');
+        assert_eq!(Word::from("").accent(), None);
+    }
+
+    #[test]
+    fn test_tilde_general() {
+        for s in [
+            "canción",
+            "café",
+            "compás",
+            "árbol",
+            "bíceps",
+            "médico",
+            "dígaselo",
+        ] {
+            assert_eq!(tilde(s), Tilde::Required, "{}", s);
+        }
+        for s in [
+            "casa", "examen", "reloj", "virrey", "robots", "sol", "fue", "guion", "curry", "henry",
+            "whisky", "ponys",
+        ] {
+            assert_eq!(tilde(s), Tilde::Forbidden, "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_tilde_hiatus() {
+        for s in [
+            "país",
+            "reúne",
+            "búho",
+            "oír",
+            "prohíbe",
+            "caída",
+            "huía",
+            "construía",
+        ] {
+            assert_eq!(tilde(s), Tilde::Required, "{}", s);
+        }
+    }
+
+    fn check(s: &str, stress_index: Option<usize>) -> (Option<TildeError>, String) {
+        let check = Word::from(s).check_tilde(stress_index).unwrap();
+        (check.error, check.suggestion)
+    }
+
+    #[test]
+    fn test_check_tilde() {
+        assert_eq!(
+            check("camion", Some(1)),
+            (Some(TildeError::Missing), "camión".to_string())
+        );
+        assert_eq!(
+            check("examenes", Some(1)),
+            (Some(TildeError::Missing), "exámenes".to_string())
+        );
+        assert_eq!(
+            check("fué", None),
+            (Some(TildeError::Superfluous), "fue".to_string())
+        );
+        assert_eq!(
+            check("exámen", None),
+            (Some(TildeError::Superfluous), "examen".to_string())
+        );
+        assert_eq!(
+            check("cáncion", Some(1)),
+            (Some(TildeError::Misplaced), "canción".to_string())
+        );
+        assert_eq!(check("Reúne", None), (None, "Reúne".to_string()));
+        assert_eq!(check("PAÍS", None), (None, "PAÍS".to_string()));
+        assert_eq!(check("camion", None), (None, "camion".to_string()));
+        assert_eq!(check("el", None), (None, "el".to_string()));
+        for s in ["curry", "henry", "whisky"] {
+            assert_eq!(check(s, None), (None, s.to_string()));
+        }
+        assert!(Word::from("casa").check_tilde(Some(2)).is_none());
+    }
+
+    fn restress(s: &str, stress_index: usize) -> String {
+        Word::from(s).with_stress(stress_index).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_restress() {
+        assert_eq!(restress("termino", 0), "término");
+        assert_eq!(restress("termino", 2), "terminó");
+        assert_eq!(restress("término", 1), "termino");
+        assert_eq!(restress("Camion", 1), "Camión");
+        assert_eq!(restress("examen", 0), "éxamen");
+        assert_eq!(restress("reúne", 1), "reúne");
+        assert_eq!(restress("reúne", 2), "reuné");
+        assert_eq!(restress("continúo", 1), "continuo");
+        assert_eq!(restress("río", 1), "rio");
+        assert_eq!(restress("tu", 0), "tu");
+        for s in ["curry", "henry", "whisky", "virrey", "casa"] {
+            let word = Word::from(s);
+            assert_eq!(restress(s, word.stress_index), s);
+        }
+        assert!(Word::from("casa").with_stress(2).is_none());
+    }
+
+    #[test]
+    fn test_strip() {
+        for s in [
+            "término",
+            "termino",
+            "terminó",
+            "país",
+            "continúo",
+            "continuo",
+            "él",
+            "el",
+            "PAÍS",
+            "búho",
+            "pingüino",
+            "curry",
+            "henry",
+            "whisky",
+        ] {
+            let stripped = Word::from(s).strip_accents().unwrap();
+            assert!(
+                !stripped.bare.chars().any(|c| c.is_accented_vowel()),
+                "{}",
+                s
+            );
+            assert_eq!(stripped.restore().unwrap(), s);
+        }
+        let stripped = Word::from("país").strip_accents().unwrap();
+        assert_eq!(stripped.stress_index, 1);
+        assert_eq!(stripped.hiatuses, [0]);
+        assert!(Word::from("").strip_accents().is_none());
+    }
+
+    #[test]
+    fn test_tilde_diacritic() {
+        for s in ["él", "el", "Tú", "qué", "cómo", "como"] {
+            assert_eq!(tilde(s), Tilde::Diacritic, "{}", s);
+        }
+    }
+}
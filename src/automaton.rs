@@ -0,0 +1,411 @@
+//! Splitting words into syllables with a table-driven automaton.
+//!
+//! Every character is classified with [`char_class`], and the class together
+//! with the part of the syllable being read picks an [`Action`] from
+//! [`TRANSITIONS`]. Actions that depend on which vowels meet, or on the next
+//! couple of characters, resolve that in [`Automaton::apply`]. Characters are
+//! read once, left to right, and finished syllables go straight to a [`Sink`].
+//!
+//! It isn't faster than the hand-written splitter it replaced, which is kept
+//! for the tests and for `bench_split_legacy` to compare against. What it
+//! buys is one table of rules in place of nested special cases.
+
+use crate::char_util::{can_form_hiatus, can_form_triphthong, char_class, CharClass, IsVowel};
+use crate::explain::{BoundaryRule, JoinRule};
+use crate::str_util::is_consonant_pair;
+use crate::{Bounds, ParseError, Result};
+
+/// Receives each syllable as soon as it's finished.
+pub(crate) trait Sink {
+    fn push(&mut self, syllable: Bounds);
+
+    /// Called before pushing a syllable that ends at a boundary, with the
+    /// rule that placed it there.
+    fn boundary(&mut self, _rule: BoundaryRule) {}
+
+    /// Called when a rule keeps letters in the current syllable.
+    fn join(&mut self, _rule: JoinRule) {}
+}
+
+impl Sink for Vec<Bounds> {
+    fn push(&mut self, syllable: Bounds) {
+        Vec::push(self, syllable);
+    }
+}
+
+/// The part of the syllable being read.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum State {
+    Onset,
+    Nucleus,
+    Coda,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Action {
+    /// Adds the character to the onset.
+    ExtendOnset,
+    /// 'y' opening a syllable is a consonant, after another consonant it's
+    /// the nucleus, "ya", "ny".
+    OnsetY,
+    /// "q" or "g", taking a silent 'u' before 'e' or 'i' along, "que", "guiso".
+    OnsetQOrG,
+    /// The first vowel of the syllable.
+    StartNucleus,
+    /// Another vowel, which either joins the nucleus or opens a new syllable.
+    ExtendNucleus,
+    /// 'y' after a vowel, part of the nucleus unless a vowel follows, "rey",
+    /// "mayo".
+    NucleusY,
+    /// 'h' after a vowel, which may sit between the vowels of a nucleus,
+    /// "prohibir", "búho".
+    NucleusH,
+    /// Adds the character to the coda.
+    ExtendCoda,
+    /// 'y' after a consonant, opening the next syllable either as its onset
+    /// or as its nucleus, "cón-yu-ge", "po-ny".
+    CodaY,
+    /// A vowel after the coda, which hands its last consonants to the new
+    /// syllable.
+    SplitCoda,
+}
+
+use Action::*;
+
+/// The action for each state, indexed by [`CharClass`].
+const TRANSITIONS: [[Action; 5]; 3] = [
+    // Vowel, Y, H, QOrG, Consonant
+    [StartNucleus, OnsetY, ExtendOnset, OnsetQOrG, ExtendOnset],
+    [ExtendNucleus, NucleusY, NucleusH, ExtendCoda, ExtendCoda],
+    [SplitCoda, CodaY, ExtendCoda, ExtendCoda, ExtendCoda],
+];
+
+fn class_index(class: CharClass) -> usize {
+    match class {
+        CharClass::Vowel => 0,
+        CharClass::Y => 1,
+        CharClass::H => 2,
+        CharClass::QOrG => 3,
+        CharClass::Consonant => 4,
+    }
+}
+
+fn state_index(state: State) -> usize {
+    match state {
+        State::Onset => 0,
+        State::Nucleus => 1,
+        State::Coda => 2,
+    }
+}
+
+fn is_vowel(c: Option<char>) -> bool {
+    c.is_some_and(|c| c.is_vowel())
+}
+
+struct Automaton {
+    state: State,
+    syllable: Bounds,
+    /// First two characters of the nucleus, to tell diphthongs from hiatuses.
+    nucleus: (char, char),
+    /// First two characters of the coda, to find consonant pairs.
+    coda: (char, char),
+}
+
+impl Automaton {
+    fn new() -> Automaton {
+        Automaton {
+            state: State::Onset,
+            syllable: Bounds::default(),
+            nucleus: ('\0', '\0'),
+            coda: ('\0', '\0'),
+        }
+    }
+
+    fn add_nucleus(&mut self, c: char) {
+        match self.syllable.nucleus {
+            0 => self.nucleus.0 = c,
+            1 => self.nucleus.1 = c,
+            _ => {}
+        }
+        self.syllable.nucleus += 1;
+    }
+
+    fn add_coda(&mut self, c: char) {
+        match self.syllable.coda {
+            0 => self.coda.0 = c,
+            1 => self.coda.1 = c,
+            _ => {}
+        }
+        self.syllable.coda += 1;
+    }
+
+    /// Finishes the current syllable and starts the next one with `onset`
+    /// consonants and the given nucleus.
+    fn next_syllable<S: Sink>(
+        &mut self,
+        sink: &mut S,
+        rule: BoundaryRule,
+        onset: usize,
+        nucleus: &[char],
+    ) {
+        sink.boundary(rule);
+        sink.push(self.syllable);
+        self.syllable = Bounds {
+            onset,
+            ..Bounds::default()
+        };
+        for &c in nucleus {
+            self.add_nucleus(c);
+        }
+        self.state = if nucleus.is_empty() {
+            State::Onset
+        } else {
+            State::Nucleus
+        };
+    }
+
+    /// Consonants of the coda that move to the onset of the next syllable.
+    fn coda_to_onset(&self) -> (usize, BoundaryRule) {
+        match self.syllable.coda {
+            1 => (1, BoundaryRule::SingleConsonant),
+            2 if is_consonant_pair(self.coda.0, self.coda.1) => (2, BoundaryRule::ConsonantGroup),
+            2 => (1, BoundaryRule::TwoConsonants),
+            3 => (2, BoundaryRule::ConsonantCluster),
+            n => (n.saturating_sub(2), BoundaryRule::ConsonantCluster),
+        }
+    }
+
+    /// Applies an action to `c`, peeking at the two characters after it.
+    ///
+    /// Returns how many characters were consumed, or `None` if the word ends
+    /// before a "q" or "gu" is followed by a vowel.
+    #[inline(always)]
+    fn apply<S: Sink>(
+        &mut self,
+        action: Action,
+        c: char,
+        next: Option<char>,
+        after: Option<char>,
+        sink: &mut S,
+    ) -> Option<usize> {
+        match action {
+            ExtendOnset => {
+                self.syllable.onset += 1;
+            }
+            OnsetY => {
+                if self.syllable.onset == 0 {
+                    self.syllable.onset += 1;
+                } else {
+                    self.add_nucleus(c);
+                    self.state = State::Nucleus;
+                }
+            }
+            OnsetQOrG => {
+                self.syllable.onset += 1;
+                let next = next?;
+                if next.eq_ignore_ascii_case(&'u') {
+                    let after = after?;
+                    if matches!(after, 'i' | 'e' | 'í' | 'é' | 'I' | 'E' | 'Í' | 'É') {
+                        self.syllable.onset += 1;
+                        self.add_nucleus(after);
+                        self.state = State::Nucleus;
+                        return Some(3);
+                    }
+                }
+            }
+            StartNucleus => {
+                self.add_nucleus(c);
+                self.state = State::Nucleus;
+            }
+            ExtendNucleus => match self.syllable.nucleus {
+                1 if can_form_hiatus(self.nucleus.0, c) => {
+                    self.next_syllable(sink, BoundaryRule::Hiatus, 0, &[c])
+                }
+                1 => self.add_nucleus(c),
+                2 if can_form_triphthong(self.nucleus.0, self.nucleus.1, c) => self.add_nucleus(c),
+                2 if self.nucleus.1.is_weak_vowel() => {
+                    // The weak vowel joins the next one instead.
+                    self.syllable.nucleus = 1;
+                    let weak = self.nucleus.1;
+                    self.next_syllable(sink, BoundaryRule::WeakVowelBetweenVowels, 0, &[weak, c]);
+                }
+                // No nucleus holds more than three vowels.
+                _ => self.next_syllable(sink, BoundaryRule::TooManyVowels, 0, &[c]),
+            },
+            NucleusY => {
+                if is_vowel(next) {
+                    self.add_coda(c);
+                    self.state = State::Coda;
+                } else {
+                    self.add_nucleus(c);
+                }
+            }
+            NucleusH => match (next, after) {
+                (None, _) => {
+                    self.add_coda(c);
+                    self.state = State::Coda;
+                }
+                (Some(next), _) if !next.is_vowel() => {
+                    self.add_coda(c);
+                    self.next_syllable(sink, BoundaryRule::HBeforeConsonant, 1, &[]);
+                    return Some(2);
+                }
+                (Some(next), _)
+                    if self.syllable.nucleus == 1 && can_form_hiatus(self.nucleus.0, next) =>
+                {
+                    self.next_syllable(sink, BoundaryRule::HiatusWithH, 1, &[next]);
+                    return Some(2);
+                }
+                (Some(next), None) => {
+                    sink.join(JoinRule::IntercalatedH);
+                    self.add_nucleus(c);
+                    self.add_nucleus(next);
+                    self.state = State::Coda;
+                    return Some(2);
+                }
+                (Some(next), Some(after)) if after.is_vowel() => {
+                    self.next_syllable(sink, BoundaryRule::HBeforeDiphthong, 1, &[next, after]);
+                    return Some(3);
+                }
+                (Some(next), Some(after)) => {
+                    sink.join(JoinRule::IntercalatedH);
+                    self.add_nucleus(c);
+                    self.add_nucleus(next);
+                    self.add_coda(after);
+                    self.state = State::Coda;
+                    return Some(3);
+                }
+            },
+            ExtendCoda => {
+                self.add_coda(c);
+                self.state = State::Coda;
+            }
+            CodaY => match self.syllable.coda {
+                1 if is_vowel(next) => self.next_syllable(sink, BoundaryRule::YBeforeVowel, 1, &[]),
+                1 => {
+                    self.syllable.coda = 0;
+                    self.next_syllable(sink, BoundaryRule::YAsVowel, 1, &[c]);
+                }
+                2 => {
+                    let (onset, rule) = self.coda_to_onset();
+                    self.syllable.coda -= onset;
+                    self.next_syllable(sink, rule, onset, &[c]);
+                }
+                _ => self.add_coda(c),
+            },
+            SplitCoda => {
+                let (onset, rule) = self.coda_to_onset();
+                self.syllable.coda -= onset;
+                self.next_syllable(sink, rule, onset, &[c]);
+            }
+        }
+        Some(1)
+    }
+}
+
+/// Splits a word into syllables, passing the number of characters in the
+/// parts of each syllable to `sink`.
+///
+/// Syllables cover all characters, in order, so slicing the input by the
+/// accumulated lengths gives the syllables back.
+pub(crate) fn split<S: Sink>(chars: impl IntoIterator<Item = char>, sink: &mut S) -> Result<()> {
+    let mut chars = chars.into_iter().fuse();
+    let mut window = [chars.next(), chars.next(), chars.next()];
+    let Some(first) = window[0] else {
+        return Ok(());
+    };
+    if window[1].is_none() {
+        // A lone letter is a syllable of its own, "y", "a".
+        if char_class(first) == CharClass::QOrG {
+            return Err(ParseError::IncompleteOnset { offset: 0 });
+        }
+        sink.push(Bounds {
+            onset: 0,
+            nucleus: 1,
+            coda: 0,
+        });
+        return Ok(());
+    }
+
+    let mut automaton = Automaton::new();
+    let mut index = 0;
+    while let Some(c) = window[0] {
+        let action = TRANSITIONS[state_index(automaton.state)][class_index(char_class(c))];
+        let consumed = automaton
+            .apply(action, c, window[1], window[2], sink)
+            .ok_or(ParseError::IncompleteOnset { offset: index })?;
+        window = match consumed {
+            1 => [window[1], window[2], chars.next()],
+            2 => [window[2], chars.next(), chars.next()],
+            _ => [chars.next(), chars.next(), chars.next()],
+        };
+        index += consumed;
+    }
+    sink.push(automaton.syllable);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::legacy;
+    use test::Bencher;
+
+    /// Words in the string literals of a test file, with hyphens removed.
+    fn words(source: &str) -> Vec<String> {
+        source
+            .split('"')
+            .skip(1)
+            .step_by(2)
+            .map(|s| s.replace('-', ""))
+            .filter(|s| !s.is_empty())
+            .collect()
+    }
+
+    fn bounds(word: &str) -> Result<Vec<Bounds>> {
+        let mut bounds = vec![];
+        split(word.chars(), &mut bounds).map(|_| bounds)
+    }
+
+    #[test]
+    fn test_same_as_legacy() {
+        let mut data = words(include_str!("../tests/split.rs"));
+        data.extend(words(include_str!("../tests/syllabize.rs")));
+        assert!(data.len() > 150, "{}", data.len());
+        for word in data {
+            let chars = word.chars().collect::<Vec<char>>();
+            assert_eq!(bounds(&word), legacy::syllable_bounds(&chars), "{}", word);
+        }
+    }
+
+    #[test]
+    fn test_split() {
+        assert_eq!(
+            bounds("guion"),
+            Ok(vec![Bounds {
+                onset: 2,
+                nucleus: 2,
+                coda: 1
+            }])
+        );
+        assert_eq!(bounds(""), Ok(vec![]));
+        assert_eq!(bounds("g"), Err(ParseError::IncompleteOnset { offset: 0 }));
+        assert_eq!(bounds("gu"), Err(ParseError::IncompleteOnset { offset: 0 }));
+        assert_eq!(
+            bounds("squ"),
+            Err(ParseError::IncompleteOnset { offset: 1 })
+        );
+    }
+
+    #[bench]
+    fn bench_split(b: &mut Bencher) {
+        let chars = "envergadura".chars().collect::<Vec<char>>();
+        b.iter(|| crate::syllable_bounds(&chars));
+    }
+
+    #[bench]
+    fn bench_split_legacy(b: &mut Bencher) {
+        let chars = "envergadura".chars().collect::<Vec<char>>();
+        b.iter(|| legacy::syllable_bounds(&chars));
+    }
+}
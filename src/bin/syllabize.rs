@@ -1,9 +1,54 @@
 use std::env;
+use std::process;
+use syllabize_es::explain::{explain, Language};
 use syllabize_es::Word;
 
+const USAGE: &str = "usage: syllabize [--explain[=en|es]] <word>";
+
 fn main() {
-    let args = env::args().collect::<Vec<String>>();
-    let word = &args[1];
-    let res: Word = word.as_str().into();
-    println!("{}", res.syllabize("-"));
+    let mut explain_in = None;
+    let mut word = None;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--explain" | "--explain=en" => explain_in = Some(Language::English),
+            "--explain=es" => explain_in = Some(Language::Spanish),
+            _ if arg.starts_with("--") => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+            _ => word = Some(arg),
+        }
+    }
+    let Some(word) = word else {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    };
+    let Some(language) = explain_in else {
+        let res: Word = word.as_str().into();
+        println!("{}", res.syllabize("-"));
+        return;
+    };
+
+    let explanation = explain(&word);
+    let syllables = &explanation.word.syllables;
+    println!("{}", explanation.word.syllabize("-"));
+    for (i, rule) in explanation.boundaries.iter().enumerate() {
+        println!(
+            "{}-{}: {}",
+            syllables[i],
+            syllables[i + 1],
+            rule.describe(language)
+        );
+    }
+    for (i, rule) in &explanation.joins {
+        println!("{}: {}", syllables[*i], rule.describe(language));
+    }
+    if let Some(rule) = explanation.stress {
+        let stressed = &syllables[explanation.word.stress_index];
+        let label = match language {
+            Language::English => "stress on",
+            Language::Spanish => "acento en",
+        };
+        println!("{} {}: {}", label, stressed, rule.describe(language));
+    }
 }
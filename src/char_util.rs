@@ -6,6 +6,7 @@ pub trait IsVowel: private::Sealed {
     fn is_stressed_vowel(&self) -> bool;
     fn is_accented_vowel(&self) -> bool;
     fn is_soft_c_trigger(&self) -> bool;
+    fn can_be_nucleus(&self) -> bool;
 }
 
 impl IsVowel for char {
@@ -71,6 +72,12 @@ impl IsVowel for char {
             'e' | 'i' | 'é' | 'í' | 'É' | 'Í' | 'E' | 'I' | 'y' | 'Y'
         )
     }
+
+    /// Vowels, plus 'y', which acts as a weak vowel at the end of a word or
+    /// before a consonant, e.g. "muy", "estoy".
+    fn can_be_nucleus(&self) -> bool {
+        self.is_vowel() || matches!(self, 'y' | 'Y')
+    }
 }
 
 mod private {
@@ -93,7 +100,7 @@ pub enum ComboType {
 pub fn combo_type(a: char, b: char) -> ComboType {
     match a {
         'e' | 'a' | 'E' | 'A' | 'á' | 'é' | 'Á' | 'É' => match b {
-            'i' | 'u' | 'I' | 'U' => ComboType::Diphthong(DiphthongType::Falling),
+            'i' | 'u' | 'y' | 'I' | 'U' | 'Y' => ComboType::Diphthong(DiphthongType::Falling),
             'a' | 'e' | 'o' | 'ü' | 'A' | 'E' | 'O' | 'Ü' => {
                 ComboType::Hiatus(HiatusType::Simple)
             }
@@ -114,7 +121,9 @@ pub fn combo_type(a: char, b: char) -> ComboType {
             _ => ComboType::Other,
         },
         'o' | 'O' => match b {
-            'i' | 'u' | 'ü' | 'I' | 'U' | 'Ü' => ComboType::Diphthong(DiphthongType::Falling),
+            'i' | 'u' | 'ü' | 'y' | 'I' | 'U' | 'Ü' | 'Y' => {
+                ComboType::Diphthong(DiphthongType::Falling)
+            }
             'a' | 'e' | 'o' | 'A' | 'E' | 'O' => ComboType::Hiatus(HiatusType::Simple),
             'á' | 'é' | 'í' | 'ó' | 'ú' | 'Á' | 'É' | 'Í' | 'Ó' | 'Ú' => {
                 ComboType::Hiatus(HiatusType::Accentual)
@@ -125,7 +134,8 @@ pub fn combo_type(a: char, b: char) -> ComboType {
             'a' | 'e' | 'o' | 'á' | 'é' | 'ó' | 'A' | 'E' | 'O' | 'Á' | 'É' | 'Ó' => {
                 ComboType::Diphthong(DiphthongType::Rising)
             }
-            'i' | 'I' => ComboType::Diphthong(DiphthongType::Homogenous),
+            // A tilde on the 'i' doesn't break the diphthong, "construí".
+            'i' | 'í' | 'y' | 'I' | 'Í' | 'Y' => ComboType::Diphthong(DiphthongType::Homogenous),
             'u' | 'ü' | 'U' | 'Ü' => ComboType::Hiatus(HiatusType::Simple),
             'ú' | 'Ú' => ComboType::Hiatus(HiatusType::Accentual),
             _ => ComboType::Other,
@@ -140,7 +150,9 @@ pub fn combo_type(a: char, b: char) -> ComboType {
             _ => ComboType::Other,
         },
         'ó' | 'Ó' => match b {
-            'i' | 'u' | 'ü' | 'I' | 'U' | 'Ü' => ComboType::Diphthong(DiphthongType::Falling),
+            'i' | 'u' | 'ü' | 'y' | 'I' | 'U' | 'Ü' | 'Y' => {
+                ComboType::Diphthong(DiphthongType::Falling)
+            }
             'a' | 'e' | 'o' | 'á' | 'é' | 'í' | 'ó' | 'ú' | 'A' | 'E' | 'O' | 'Á' | 'É' | 'Í'
             | 'Ó' | 'Ú' => ComboType::Hiatus(HiatusType::Accentual),
             _ => ComboType::Other,
@@ -149,13 +161,143 @@ pub fn combo_type(a: char, b: char) -> ComboType {
     }
 }
 
+/// Returns true if the character is a letter of the Spanish alphabet,
+/// accented vowels included.
+pub fn is_letter(c: char) -> bool {
+    c.is_ascii_alphabetic() || c.is_vowel() || matches!(c, 'ñ' | 'Ñ')
+}
+
+/// Removes the acute accent from a vowel, other characters are returned as
+/// they are.
+pub fn strip_accent(c: char) -> char {
+    match c {
+        'á' => 'a',
+        'é' => 'e',
+        'í' => 'i',
+        'ó' => 'o',
+        'ú' => 'u',
+        'Á' => 'A',
+        'É' => 'E',
+        'Í' => 'I',
+        'Ó' => 'O',
+        'Ú' => 'U',
+        _ => c,
+    }
+}
+
+/// Adds an acute accent to a vowel, other characters are returned as they
+/// are.
+pub fn add_accent(c: char) -> char {
+    match c {
+        'a' => 'á',
+        'e' => 'é',
+        'i' => 'í',
+        'o' => 'ó',
+        'u' => 'ú',
+        'A' => 'Á',
+        'E' => 'É',
+        'I' => 'Í',
+        'O' => 'Ó',
+        'U' => 'Ú',
+        _ => c,
+    }
+}
+
+/// Classes of characters that the syllabizer treats differently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharClass {
+    /// Any vowel, accented or with diaeresis, but not 'y'.
+    Vowel,
+    /// 'y', a consonant before a vowel and a vowel otherwise.
+    Y,
+    /// 'h', silent and allowed between the vowels of a nucleus.
+    H,
+    /// 'q' or 'g', which may be followed by a silent 'u'.
+    QOrG,
+    /// Anything else.
+    Consonant,
+}
+
+/// Classes of ASCII characters, looked up instead of matched since every
+/// character of a word goes through [`char_class`].
+const ASCII_CLASSES: [CharClass; 128] = {
+    let mut classes = [CharClass::Consonant; 128];
+    let mut i = 0;
+    while i < 128 {
+        classes[i] = match (i as u8).to_ascii_lowercase() {
+            b'a' | b'e' | b'i' | b'o' | b'u' => CharClass::Vowel,
+            b'y' => CharClass::Y,
+            b'h' => CharClass::H,
+            b'q' | b'g' => CharClass::QOrG,
+            _ => CharClass::Consonant,
+        };
+        i += 1;
+    }
+    classes
+};
+
+/// Returns the class of a character, ignoring case.
+#[inline]
+pub fn char_class(c: char) -> CharClass {
+    match ASCII_CLASSES.get(c as usize) {
+        Some(&class) => class,
+        None if c.is_vowel() => CharClass::Vowel,
+        None => CharClass::Consonant,
+    }
+}
+
+/// Combining acute accent, as in NFD "a\u{301}" for "á".
+pub const COMBINING_ACUTE: char = '\u{301}';
+/// Combining tilde, as in NFD "n\u{303}" for "ñ".
+pub const COMBINING_TILDE: char = '\u{303}';
+/// Combining diaeresis, as in NFD "u\u{308}" for "ü".
+pub const COMBINING_DIAERESIS: char = '\u{308}';
+
+/// Returns true for characters in the Unicode combining mark blocks.
+pub fn is_combining_mark(c: char) -> bool {
+    matches!(
+        c,
+        '\u{300}'..='\u{36f}'
+            | '\u{1ab0}'..='\u{1aff}'
+            | '\u{1dc0}'..='\u{1dff}'
+            | '\u{20d0}'..='\u{20ff}'
+            | '\u{fe20}'..='\u{fe2f}'
+    )
+}
+
+/// Returns the precomposed letter for a base letter and a combining mark, if
+/// it's one used in Spanish.
+pub fn compose(base: char, mark: char) -> Option<char> {
+    let composed = match (mark, base) {
+        (COMBINING_ACUTE, 'a') => 'á',
+        (COMBINING_ACUTE, 'e') => 'é',
+        (COMBINING_ACUTE, 'i') => 'í',
+        (COMBINING_ACUTE, 'o') => 'ó',
+        (COMBINING_ACUTE, 'u') => 'ú',
+        (COMBINING_ACUTE, 'A') => 'Á',
+        (COMBINING_ACUTE, 'E') => 'É',
+        (COMBINING_ACUTE, 'I') => 'Í',
+        (COMBINING_ACUTE, 'O') => 'Ó',
+        (COMBINING_ACUTE, 'U') => 'Ú',
+        (COMBINING_DIAERESIS, 'u') => 'ü',
+        (COMBINING_DIAERESIS, 'U') => 'Ü',
+        (COMBINING_TILDE, 'n') => 'ñ',
+        (COMBINING_TILDE, 'N') => 'Ñ',
+        _ => return None,
+    };
+    Some(composed)
+}
+
 pub fn can_form_hiatus(a: char, b: char) -> bool {
     matches!(combo_type(a, b), ComboType::Hiatus(_))
 }
 
 /// Returns true if three characters make a triphthong.
+///
+/// 'y' may close a triphthong, as in "Paraguay", but can't open one, since
+/// before a vowel it's a consonant.
 pub fn can_form_triphthong(a: char, b: char, c: char) -> bool {
-    a.is_weak_vowel() && b.is_stressed_vowel() && c.is_weak_vowel()
+    a.is_weak_vowel() && !matches!(a, 'y' | 'Y') && b.is_stressed_vowel() && c.is_weak_vowel()
 }
 
 #[cfg(test)]
@@ -165,21 +307,88 @@ mod tests {
 
     #[test]
     fn test_is_triphthong() {
-        assert_eq!(can_form_triphthong('a', 'b', 'c'), false);
-        assert_eq!(can_form_triphthong('i', 'e', 'i'), true);
-        assert_eq!(can_form_triphthong('i', 'á', 'i'), true);
+        assert!(!can_form_triphthong('a', 'b', 'c'));
+        assert!(can_form_triphthong('i', 'e', 'i'));
+        assert!(can_form_triphthong('i', 'á', 'i'));
+        assert!(can_form_triphthong('u', 'a', 'y'));
+        assert!(can_form_triphthong('U', 'A', 'Y'));
+        assert!(!can_form_triphthong('y', 'a', 'i'));
+    }
+
+    #[test]
+    fn test_combo_type_y() {
+        for (a, b) in [('e', 'y'), ('o', 'y'), ('a', 'y'), ('E', 'Y')] {
+            assert!(matches!(
+                combo_type(a, b),
+                ComboType::Diphthong(DiphthongType::Falling)
+            ));
+        }
+        assert!(matches!(
+            combo_type('u', 'y'),
+            ComboType::Diphthong(DiphthongType::Homogenous)
+        ));
+    }
+
+    #[test]
+    fn test_combo_type_ui() {
+        for (a, b) in [('u', 'i'), ('u', 'í'), ('i', 'u'), ('i', 'ú'), ('ü', 'í')] {
+            assert!(matches!(
+                combo_type(a, b),
+                ComboType::Diphthong(DiphthongType::Homogenous)
+            ));
+        }
     }
 
     #[test]
     fn test_is_hiatus() {
-        assert_eq!(can_form_hiatus('a', 'b'), false);
-        assert_eq!(can_form_hiatus('a', 'e'), true);
+        assert!(!can_form_hiatus('a', 'b'));
+        assert!(can_form_hiatus('a', 'e'));
+    }
+
+    #[test]
+    fn test_is_letter() {
+        assert!(is_letter('ñ'));
+        assert!(is_letter('Ü'));
+        assert!(!is_letter('1'));
+        assert!(!is_letter('-'));
+    }
+
+    #[test]
+    fn test_strip_accent() {
+        assert_eq!(strip_accent('á'), 'a');
+        assert_eq!(strip_accent('Ú'), 'U');
+        assert_eq!(strip_accent('ü'), 'ü');
+        assert_eq!(strip_accent('n'), 'n');
+        assert_eq!(add_accent('o'), 'ó');
+        assert_eq!(add_accent('I'), 'Í');
+        assert_eq!(add_accent('y'), 'y');
+    }
+
+    #[test]
+    fn test_char_class() {
+        assert_eq!(char_class('á'), CharClass::Vowel);
+        assert_eq!(char_class('Y'), CharClass::Y);
+        assert_eq!(char_class('h'), CharClass::H);
+        assert_eq!(char_class('G'), CharClass::QOrG);
+        assert_eq!(char_class('ñ'), CharClass::Consonant);
+    }
+
+    #[test]
+    fn test_compose() {
+        assert_eq!(compose('o', COMBINING_ACUTE), Some('ó'));
+        assert_eq!(compose('U', COMBINING_DIAERESIS), Some('Ü'));
+        assert_eq!(compose('n', COMBINING_TILDE), Some('ñ'));
+        assert_eq!(compose('a', '\u{300}'), None);
+        assert!(is_combining_mark('\u{300}'));
+        assert!(!is_combining_mark('a'));
     }
 
     #[test]
     fn test_is_vowel() {
-        assert_eq!('e'.is_vowel(), true);
-        assert_eq!('f'.is_vowel(), false);
+        assert!('e'.is_vowel());
+        assert!(!'f'.is_vowel());
+        assert!(!'y'.is_vowel());
+        assert!('Y'.can_be_nucleus());
     }
 
     #[bench]
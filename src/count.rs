@@ -0,0 +1,149 @@
+//! Counting syllables and finding the stress without building a [`Word`].
+//!
+//! [`Word`]: crate::Word
+
+use std::cell::Cell;
+
+use crate::automaton::{self, Sink};
+use crate::char_util::IsVowel;
+use crate::str_util::composed;
+use crate::{unaccented_stress, Bounds, Ending};
+
+/// Keeps what the stress rules need while syllables go by.
+struct Scan<'a> {
+    /// Index of the last accented vowel read so far.
+    accent: &'a Cell<Option<usize>>,
+    count: usize,
+    /// Index of the first character of the next syllable.
+    start: usize,
+    accented_syllable: Option<usize>,
+    last: Bounds,
+}
+
+impl Sink for Scan<'_> {
+    fn push(&mut self, syllable: Bounds) {
+        let end = self.start + syllable.len();
+        // Once the last accented vowel of the word is read, no other comes
+        // along, so the syllable holding it is the last to match.
+        if self
+            .accent
+            .get()
+            .is_some_and(|index| (self.start..end).contains(&index))
+        {
+            self.accented_syllable = Some(self.count);
+        }
+        self.count += 1;
+        self.start = end;
+        self.last = syllable;
+    }
+}
+
+/// Counts the syllables of a word and finds the stressed one in a single
+/// pass, without allocating.
+///
+/// Agrees with the `syllables.len()` and `stress_index` of [`Word::from`],
+/// so words that can't be syllabized give `(0, 0)`.
+///
+/// ```
+/// use syllabize_es::count_and_stress;
+/// assert_eq!(count_and_stress("envergadura"), (5, 3));
+/// assert_eq!(count_and_stress("canción"), (2, 1));
+/// ```
+///
+/// [`Word::from`]: crate::Word
+pub fn count_and_stress(word: &str) -> (usize, usize) {
+    let accent = Cell::new(None);
+    let mut last_char = None;
+    let chars = composed(word).enumerate().map(|(index, (_, c))| {
+        if c.is_accented_vowel() {
+            accent.set(Some(index));
+        }
+        last_char = Some(c);
+        c
+    });
+    let mut scan = Scan {
+        accent: &accent,
+        count: 0,
+        start: 0,
+        accented_syllable: None,
+        last: Bounds::default(),
+    };
+    if automaton::split(chars, &mut scan).is_err() {
+        return (0, 0);
+    }
+
+    let stress_index = match (scan.count, scan.accented_syllable) {
+        (0 | 1, _) => 0,
+        (_, Some(index)) => index,
+        (count, None) => {
+            let ending = Ending {
+                nucleus_len: scan.last.nucleus,
+                coda_len: scan.last.coda,
+                last_char,
+            };
+            unaccented_stress(count, &ending).0
+        }
+    };
+    (scan.count, stress_index)
+}
+
+/// Counts the syllables of a word without building them.
+///
+/// Same as `Word::from(word).syllables.len()`, words that can't be
+/// syllabized have none.
+///
+/// ```
+/// use syllabize_es::count_syllables;
+/// assert_eq!(count_syllables("Paraguay"), 3);
+/// assert_eq!(count_syllables("nn"), 1);
+/// assert_eq!(count_syllables("gu"), 0);
+/// ```
+pub fn count_syllables(word: &str) -> usize {
+    count_and_stress(word).0
+}
+
+/// Index of the stressed syllable, same as `Word::from(word).stress_index`.
+///
+/// ```
+/// use syllabize_es::stress_position;
+/// assert_eq!(stress_position("árbol"), 0);
+/// assert_eq!(stress_position("reloj"), 1);
+/// ```
+pub fn stress_position(word: &str) -> usize {
+    count_and_stress(word).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Word;
+    use test::Bencher;
+
+    #[test]
+    fn test_count_and_stress() {
+        for s in [
+            "",
+            "a",
+            "ahí",
+            "estoy",
+            "CANCIONES",
+            "cancio\u{301}n",
+            "búho",
+            "nn",
+            "qu",
+        ] {
+            let word = Word::from(s);
+            assert_eq!(
+                count_and_stress(s),
+                (word.syllables.len(), word.stress_index),
+                "{}",
+                s
+            );
+        }
+    }
+
+    #[bench]
+    fn bench_count_syllables(b: &mut Bencher) {
+        b.iter(|| count_syllables("envergadura"));
+    }
+}
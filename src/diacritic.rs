@@ -0,0 +1,313 @@
+//! Diacritic tildes, which tell apart words spelled the same, and where
+//! running text is likely missing one.
+
+use crate::accent::DIACRITIC_PAIRS;
+use crate::char_util::strip_accent;
+use crate::str_util::match_case;
+
+/// Grammatical role implied by the spelling of a diacritic word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    /// "el libro".
+    Article,
+    /// "él", "tú", "mí", "te", "se".
+    Pronoun,
+    /// "tu casa", "mi casa".
+    Possessive,
+    /// "el té".
+    Noun,
+    /// "sé", "dé".
+    Verb,
+    /// "de".
+    Preposition,
+    /// "si", "mas".
+    Conjunction,
+    /// "sí", "más", "aun", "aún".
+    Adverb,
+    /// "el libro que leí", "como quieras".
+    Relative,
+    /// Interrogatives and exclamatives, "¿qué?", "¡cómo llueve!".
+    Interrogative,
+}
+
+/// A word from [`DIACRITIC_LEXICON`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiacriticWord {
+    /// The word in lowercase, with its tilde if it has one.
+    pub spelling: &'static str,
+    pub role: Role,
+    /// Whether the word is stressed in speech. The tilde goes on the tonic
+    /// one of each pair.
+    pub tonic: bool,
+}
+
+const fn entry(spelling: &'static str, role: Role, tonic: bool) -> DiacriticWord {
+    DiacriticWord {
+        spelling,
+        role,
+        tonic,
+    }
+}
+
+/// Both spellings of every pair in [`DIACRITIC_PAIRS`], with their usual
+/// role.
+pub const DIACRITIC_LEXICON: &[DiacriticWord] = &[
+    entry("el", Role::Article, false),
+    entry("él", Role::Pronoun, true),
+    entry("tu", Role::Possessive, false),
+    entry("tú", Role::Pronoun, true),
+    entry("mi", Role::Possessive, false),
+    entry("mí", Role::Pronoun, true),
+    entry("te", Role::Pronoun, false),
+    entry("té", Role::Noun, true),
+    entry("se", Role::Pronoun, false),
+    entry("sé", Role::Verb, true),
+    entry("de", Role::Preposition, false),
+    entry("dé", Role::Verb, true),
+    entry("si", Role::Conjunction, false),
+    entry("sí", Role::Adverb, true),
+    entry("mas", Role::Conjunction, false),
+    entry("más", Role::Adverb, true),
+    // "aun" means "even", "aún" means "still".
+    entry("aun", Role::Adverb, false),
+    entry("aún", Role::Adverb, true),
+    entry("que", Role::Relative, false),
+    entry("qué", Role::Interrogative, true),
+    entry("quien", Role::Relative, false),
+    entry("quién", Role::Interrogative, true),
+    entry("cual", Role::Relative, false),
+    entry("cuál", Role::Interrogative, true),
+    entry("como", Role::Relative, false),
+    entry("cómo", Role::Interrogative, true),
+    entry("donde", Role::Relative, false),
+    entry("dónde", Role::Interrogative, true),
+    entry("adonde", Role::Relative, false),
+    entry("adónde", Role::Interrogative, true),
+    entry("cuando", Role::Relative, false),
+    entry("cuándo", Role::Interrogative, true),
+    entry("cuanto", Role::Relative, false),
+    entry("cuánto", Role::Interrogative, true),
+];
+
+/// Looks up a word in [`DIACRITIC_LEXICON`], ignoring case but not the
+/// tilde, or returns `None` if it isn't a diacritic word.
+///
+/// # Example
+///
+/// ```
+/// use syllabize_es::diacritic::{classify, Role};
+/// let word = classify("Él").unwrap();
+/// assert_eq!(word.role, Role::Pronoun);
+/// assert!(word.tonic);
+/// assert!(!classify("el").unwrap().tonic);
+/// assert_eq!(classify("casa"), None);
+/// ```
+pub fn classify(word: &str) -> Option<DiacriticWord> {
+    let lower = word.to_lowercase();
+    DIACRITIC_LEXICON
+        .iter()
+        .find(|w| w.spelling == lower)
+        .copied()
+}
+
+/// Why a word in running text likely needs its diacritic tilde.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Context {
+    /// It opens a question, "¿que hora es?".
+    Question,
+    /// It opens an exclamation, "¡que frío!".
+    Exclamation,
+    /// It follows a verb of knowing or asking, "no sé donde está".
+    IndirectQuestion,
+}
+
+/// A word in running text that is likely missing its diacritic tilde.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiacriticHint {
+    /// Byte offset of the word in the text.
+    pub offset: usize,
+    /// The word as written.
+    pub word: String,
+    /// The word with its tilde, in the same case.
+    pub suggestion: String,
+    pub context: Context,
+}
+
+/// Prepositions that can come before an interrogative, "¿de qué?",
+/// "¿por qué?".
+const PREPOSITIONS: &[&str] = &[
+    "a", "con", "de", "desde", "en", "hacia", "hasta", "para", "por", "sin", "sobre",
+];
+
+/// Conjunctions that can come before an interrogative, "¿y qué?", "¿pero
+/// cómo?".
+const CONJUNCTIONS: &[&str] = &["y", "e", "o", "u", "ni", "pero", "pues"];
+
+/// Verbs that introduce indirect questions, "no sé qué hacer", "pregunta
+/// dónde está".
+const QUESTION_VERBS: &[&str] = &[
+    "sé",
+    "sabes",
+    "sabe",
+    "sabemos",
+    "sabéis",
+    "saben",
+    "saber",
+    "sabía",
+    "pregunto",
+    "preguntas",
+    "pregunta",
+    "preguntó",
+    "preguntar",
+    "dime",
+    "decidir",
+    "imagina",
+];
+
+/// Finds interrogatives and exclamatives written without their tilde in
+/// running text.
+///
+/// A word is flagged when it opens a question or exclamation, after an
+/// optional conjunction or preposition, or when it follows a verb of knowing or asking.
+/// "que" after such a verb is usually a conjunction, "sé que vendrá", so it's
+/// only flagged before an infinitive, "no sé que hacer". Other uses are left
+/// alone, since telling them apart needs more than the neighbouring words.
+///
+/// # Example
+///
+/// ```
+/// use syllabize_es::diacritic::{check_text, Context};
+/// let hints = check_text("¿Que hora es? No sé donde está. Sé que vendrá.");
+/// assert_eq!(hints.len(), 2);
+/// assert_eq!(hints[0].suggestion, "Qué");
+/// assert_eq!(hints[0].context, Context::Question);
+/// assert_eq!(hints[1].suggestion, "dónde");
+/// assert_eq!(hints[1].context, Context::IndirectQuestion);
+/// ```
+pub fn check_text(text: &str) -> Vec<DiacriticHint> {
+    let words = tokenize(text);
+    let mut hints = vec![];
+    for (i, token) in words.iter().enumerate() {
+        let Some(tonic) = tonic_spelling(token.text) else {
+            continue;
+        };
+        let previous = i.checked_sub(1).map(|p| &words[p]);
+        let context = match token.opens {
+            Some('¿') => Some(Context::Question),
+            Some(_) => Some(Context::Exclamation),
+            None => previous
+                .filter(|p| !token.after_punctuation && is_question_verb(p.text))
+                .filter(|_| {
+                    tonic != "qué" || words.get(i + 1).is_some_and(|n| is_infinitive(n.text))
+                })
+                .map(|_| Context::IndirectQuestion),
+        };
+        if let Some(context) = context {
+            hints.push(DiacriticHint {
+                offset: token.offset,
+                word: token.text.to_string(),
+                suggestion: match_case(token.text, tonic),
+                context,
+            });
+        }
+    }
+    hints
+}
+
+/// The tonic spelling of an interrogative written without its tilde.
+fn tonic_spelling(word: &str) -> Option<&'static str> {
+    let plain = classify(word).filter(|w| w.role == Role::Relative)?;
+    DIACRITIC_PAIRS
+        .iter()
+        .find(|(p, _)| *p == plain.spelling)
+        .map(|(_, tonic)| *tonic)
+}
+
+fn is_question_verb(word: &str) -> bool {
+    QUESTION_VERBS.contains(&word.to_lowercase().as_str())
+}
+
+fn is_infinitive(word: &str) -> bool {
+    let lower = word
+        .chars()
+        .map(strip_accent)
+        .flat_map(char::to_lowercase)
+        .collect::<String>();
+    lower.chars().count() > 2 && ["ar", "er", "ir"].iter().any(|e| lower.ends_with(e))
+}
+
+/// A word of running text.
+struct Token<'a> {
+    offset: usize,
+    text: &'a str,
+    /// The '¿' or '¡' the word opens, possibly after a conjunction or a
+    /// preposition.
+    opens: Option<char>,
+    /// Whether punctuation separates the word from the previous one.
+    after_punctuation: bool,
+}
+
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens: Vec<Token> = vec![];
+    let mut start = None;
+    let mut opens = None;
+    let mut after_punctuation = false;
+    for (i, c) in text.char_indices().chain([(text.len(), ' ')]) {
+        if c.is_alphabetic() {
+            start.get_or_insert(i);
+            continue;
+        }
+        if let Some(s) = start.take() {
+            let word = &text[s..i];
+            tokens.push(Token {
+                offset: s,
+                text: word,
+                opens,
+                after_punctuation,
+            });
+            // "¿Por qué?" and "¿Y qué?" open the question with "qué" as well.
+            let lower = word.to_lowercase();
+            if !PREPOSITIONS.contains(&lower.as_str()) && !CONJUNCTIONS.contains(&lower.as_str()) {
+                opens = None;
+            }
+            after_punctuation = false;
+        }
+        match c {
+            '¿' | '¡' => {
+                opens = Some(c);
+                after_punctuation = true;
+            }
+            c if c.is_whitespace() => {}
+            _ => {
+                opens = None;
+                after_punctuation = true;
+            }
+        }
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lexicon() {
+        for (plain, tonic) in DIACRITIC_PAIRS {
+            assert!(!classify(plain).unwrap().tonic, "{}", plain);
+            assert!(classify(tonic).unwrap().tonic, "{}", tonic);
+        }
+        assert_eq!(DIACRITIC_LEXICON.len(), DIACRITIC_PAIRS.len() * 2);
+    }
+
+    #[test]
+    fn test_tokenize() {
+        let tokens = tokenize("¿Por que, dime?");
+        let texts = tokens.iter().map(|t| t.text).collect::<Vec<&str>>();
+        assert_eq!(texts, ["Por", "que", "dime"]);
+        assert_eq!(tokens[0].opens, Some('¿'));
+        assert_eq!(tokens[1].opens, Some('¿'));
+        assert_eq!(tokens[2].opens, None);
+        assert!(tokens[2].after_punctuation);
+    }
+}
@@ -0,0 +1,264 @@
+//! Enclitic pronouns, the clitics written after a verb form as a single word,
+//! "dámelo", "diciéndoselo".
+
+use crate::accent::restress_at;
+use crate::char_util::{add_accent, strip_accent, IsVowel};
+use crate::{StressType, Word};
+
+/// Pronouns that can be attached to the end of a verb form.
+pub const CLITICS: &[&str] = &[
+    "me", "te", "se", "lo", "la", "le", "nos", "os", "los", "las", "les",
+];
+
+/// A verb form split from the clitics attached to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Enclitics {
+    /// The verb form as written on its own, "da" for "dámelo".
+    pub verb: String,
+    /// The clitics in the order they're attached, "me" and "lo".
+    pub clitics: Vec<String>,
+}
+
+/// Splits the trailing clitics off a verb form, or returns `None` if the
+/// word doesn't look like one carrying clitics.
+///
+/// Without a dictionary of verbs this is a heuristic. Infinitives, gerunds
+/// and "vosotros" imperatives are recognized by their ending, "decirlo",
+/// "haciéndolo", "amaos". Other forms need the tilde that the clitics bring,
+/// "dámelo" is split but "dame" isn't.
+///
+/// # Example
+///
+/// ```
+/// use syllabize_es::enclitic::split_enclitics;
+/// let split = split_enclitics("diciéndoselo").unwrap();
+/// assert_eq!(split.verb, "diciendo");
+/// assert_eq!(split.clitics, ["se", "lo"]);
+/// assert_eq!(split_enclitics("decíos").unwrap().verb, "decid");
+/// assert_eq!(split_enclitics("vámonos").unwrap().verb, "vamos");
+/// assert_eq!(split_enclitics("pala"), None);
+/// ```
+pub fn split_enclitics(word: &str) -> Option<Enclitics> {
+    let chars = word.chars().collect::<Vec<char>>();
+    let lower = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect::<Vec<char>>();
+    let whole = Word::from(word);
+
+    let mut chains = vec![];
+    find_chains(&lower, lower.len(), u8::MAX, &mut vec![], &mut chains);
+    // Prefer more clitics, then longer ones, "tráiga-nos-la" over
+    // "tráigan-os-la".
+    chains.sort_by_key(|starts: &Vec<usize>| (usize::MAX - starts.len(), starts[starts.len() - 1]));
+    chains
+        .into_iter()
+        .find_map(|starts| split_at(&chars, &lower, &whole, &starts))
+}
+
+/// Collects the start indices of every chain of up to three clitics, in the
+/// RAE order, ending at `end`. Starts are listed from the last clitic.
+fn find_chains(
+    lower: &[char],
+    end: usize,
+    below: u8,
+    starts: &mut Vec<usize>,
+    chains: &mut Vec<Vec<usize>>,
+) {
+    if starts.len() == 3 {
+        return;
+    }
+    for clitic in CLITICS {
+        let len = clitic.chars().count();
+        if len >= end || !lower[end - len..end].iter().copied().eq(clitic.chars()) {
+            continue;
+        }
+        let rank = rank(clitic).unwrap_or(u8::MAX);
+        if rank >= below {
+            continue;
+        }
+        starts.push(end - len);
+        chains.push(starts.clone());
+        find_chains(lower, end - len, rank, starts, chains);
+        starts.pop();
+    }
+}
+
+/// Splits the word at the clitics starting at `starts`, if what's left reads
+/// as a verb form.
+fn split_at(chars: &[char], lower: &[char], whole: &Word, starts: &[usize]) -> Option<Enclitics> {
+    let base_end = starts[starts.len() - 1];
+    let first_end = match starts.len() {
+        1 => lower.len(),
+        n => starts[n - 2],
+    };
+    let first = lower[base_end..first_end].iter().collect::<String>();
+    // Clitics are never stressed, "dámelo".
+    if whole.accent()?.position >= base_end {
+        return None;
+    }
+    let mut verb = chars[..base_end].to_vec();
+    let base = lower[..base_end]
+        .iter()
+        .map(|c| strip_accent(*c))
+        .collect::<String>();
+    let last = *verb.last()?;
+    let restored = |c: char| {
+        if last.is_uppercase() {
+            c.to_ascii_uppercase()
+        } else {
+            c
+        }
+    };
+
+    let plausible = if first == "os" {
+        // "decid" loses the 'd' before "os", except "idos".
+        if base != "id" {
+            if !last.is_vowel() {
+                return None;
+            }
+            verb.push(restored('d'));
+        }
+        true
+    } else {
+        // "vamos" loses the 's' before "nos" and "se", "vámonos".
+        if (first == "nos" || first == "se") && base.ends_with("mo") {
+            verb.push(restored('s'));
+        }
+        let has_tilde = chars[..base_end].iter().any(|c| c.is_accented_vowel());
+        is_infinitive(&base, &chars[..base_end].iter().collect::<String>())
+            || base.ends_with("ndo")
+            || is_vosotros_imperative(&base)
+            || (has_tilde
+                && matches!(
+                    whole.stress(),
+                    Some(StressType::Proparoxytone | StressType::Superproparoxytone)
+                ))
+    };
+    if !plausible {
+        return None;
+    }
+
+    let verb = verb.into_iter().collect::<String>();
+    let parsed = Word::from(verb.as_str());
+    if parsed.syllables.is_empty() || (first == "os" && base != "id" && parsed.syllables.len() < 2)
+    {
+        return None;
+    }
+    let position = parsed.accent()?.position;
+    let clitics = starts
+        .iter()
+        .rev()
+        .zip(starts.iter().rev().skip(1).chain([&chars.len()]))
+        .map(|(&start, &end)| chars[start..end].iter().collect())
+        .collect();
+    Some(Enclitics {
+        verb: restress_at(&verb, position)?,
+        clitics,
+    })
+}
+
+/// Whether a word ends like an infinitive, "decir", "oír". `base` is the
+/// word in lowercase without tildes. Only a few infinitives are
+/// monosyllables, which keeps out "parte" or "fuerte".
+fn is_infinitive(base: &str, written: &str) -> bool {
+    ["ar", "er", "ir"].iter().any(|e| base.ends_with(e))
+        && (Word::from(written).syllables.len() > 1 || ["dar", "ver", "ser", "ir"].contains(&base))
+}
+
+/// Whether a word without tildes ends like "decid", "amad".
+fn is_vosotros_imperative(base: &str) -> bool {
+    ["ad", "ed", "id"].iter().any(|e| base.ends_with(e)) && Word::from(base).syllables.len() > 1
+}
+
+/// Position of a clitic in the RAE order "se", "te"/"os", "me"/"nos", then
+/// the third person ones, "diciéndoselo", "cómetelo".
+fn rank(clitic: &str) -> Option<u8> {
+    match clitic {
+        "se" => Some(0),
+        "te" | "os" => Some(1),
+        "me" | "nos" => Some(2),
+        "lo" | "la" | "le" | "los" | "las" | "les" => Some(3),
+        _ => None,
+    }
+}
+
+/// Attaches clitics to a verb form, respelling it so the stress stays on the
+/// same vowel. Returns `None` if the verb can't be syllabized, or the clitics
+/// aren't in [`CLITICS`] or not in the RAE order.
+///
+/// # Example
+///
+/// ```
+/// use syllabize_es::enclitic::attach_enclitics;
+/// assert_eq!(attach_enclitics("da", &["me", "lo"]).unwrap(), "dámelo");
+/// assert_eq!(attach_enclitics("decid", &["os"]).unwrap(), "decíos");
+/// assert_eq!(attach_enclitics("dé", &["me"]).unwrap(), "deme");
+/// assert_eq!(attach_enclitics("da", &["lo", "me"]), None);
+/// ```
+pub fn attach_enclitics(verb: &str, clitics: &[&str]) -> Option<String> {
+    let mut below = None;
+    for clitic in clitics {
+        let rank = rank(&clitic.to_lowercase())?;
+        if below.is_some_and(|b| rank <= b) {
+            return None;
+        }
+        below = Some(rank);
+    }
+    let Some(first) = clitics.first().map(|c| c.to_lowercase()) else {
+        return Some(verb.to_string());
+    };
+
+    let position = Word::from(verb).accent()?.position;
+    let lower = verb.to_lowercase();
+    let mut stem = verb.chars().collect::<Vec<char>>();
+    if (first == "os" && lower.ends_with('d') && lower != "id")
+        || ((first == "nos" || first == "se") && lower.ends_with("mos"))
+    {
+        stem.pop();
+    }
+    // A tilde on the stressed vowel keeps it from joining the clitic in a
+    // diphthong, "decíos".
+    let vowel = stem.get_mut(position)?;
+    *vowel = add_accent(strip_accent(*vowel));
+    let mut word = stem.into_iter().collect::<String>();
+    word.extend(clitics.iter().copied());
+    restress_at(&word, position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank() {
+        for clitic in CLITICS {
+            assert!(rank(clitic).is_some(), "{}", clitic);
+        }
+        assert_eq!(rank("ma"), None);
+    }
+
+    #[test]
+    fn test_find_chains() {
+        let lower = "dámelo".chars().collect::<Vec<char>>();
+        let mut chains = vec![];
+        find_chains(&lower, lower.len(), u8::MAX, &mut vec![], &mut chains);
+        assert_eq!(chains, vec![vec![4], vec![4, 2]]);
+    }
+
+    #[test]
+    fn test_case() {
+        let split = split_enclitics("DÁMELO").unwrap();
+        assert_eq!(split.verb, "DA");
+        assert_eq!(split.clitics, ["ME", "LO"]);
+        assert_eq!(split_enclitics("DECÍOS").unwrap().verb, "DECID");
+        assert_eq!(attach_enclitics("DA", &["ME", "LO"]).unwrap(), "DÁMELO");
+    }
+
+    #[test]
+    fn test_attach_nothing() {
+        assert_eq!(attach_enclitics("da", &[]).unwrap(), "da");
+        assert_eq!(attach_enclitics("", &["lo"]), None);
+        assert_eq!(attach_enclitics("da", &["ma"]), None);
+    }
+}
@@ -0,0 +1,311 @@
+//! Tracing which rule placed each syllable boundary and picked the stressed
+//! syllable of a word.
+
+use crate::automaton::{self, Sink};
+use crate::{composed_chars, stress_with_rule, Bounds, Word};
+
+/// Languages the rules can be described in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+}
+
+/// Rules that place a boundary between two syllables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundaryRule {
+    /// Two vowels that don't make a diphthong, "le-er", "pa-ís".
+    Hiatus,
+    /// Of three vowels without a triphthong, the weak one in the middle goes
+    /// with the last one.
+    WeakVowelBetweenVowels,
+    /// A vowel that doesn't fit in a nucleus that's already full.
+    TooManyVowels,
+    /// A hiatus with an 'h' in between, "bú-ho", "a-hí".
+    HiatusWithH,
+    /// 'h' opening a syllable whose nucleus is a diphthong, "a-hue-car".
+    HBeforeDiphthong,
+    /// 'h' closing a syllable before a consonant.
+    HBeforeConsonant,
+    /// A consonant between vowels goes with the second one, "pa-la-bra".
+    SingleConsonant,
+    /// Consonants that can't be split, "pa-dre", "a-tle-ta", see
+    /// [`is_consonant_group`](crate::str_util::is_consonant_group).
+    ConsonantGroup,
+    /// Two consonants between vowels are split, "al-to".
+    TwoConsonants,
+    /// Of three or more consonants, the last two go with the next vowel,
+    /// "cons-truir".
+    ConsonantCluster,
+    /// 'y' before a vowel is a consonant and opens the syllable, "cón-yu-ge".
+    YBeforeVowel,
+    /// 'y' after a consonant and before no vowel acts as one, "po-ny".
+    YAsVowel,
+}
+
+impl BoundaryRule {
+    pub fn describe(&self, language: Language) -> &'static str {
+        match language {
+            Language::English => match self {
+                BoundaryRule::Hiatus => "two vowels in hiatus go in separate syllables",
+                BoundaryRule::WeakVowelBetweenVowels => {
+                    "a weak vowel between two vowels goes with the next one"
+                }
+                BoundaryRule::TooManyVowels => "three vowels that don't make a triphthong",
+                BoundaryRule::HiatusWithH => "two vowels in hiatus with an 'h' in between",
+                BoundaryRule::HBeforeDiphthong => "'h' opens the syllable before a diphthong",
+                BoundaryRule::HBeforeConsonant => "'h' closes the syllable before a consonant",
+                BoundaryRule::SingleConsonant => {
+                    "a consonant between vowels goes with the second one"
+                }
+                BoundaryRule::ConsonantGroup => "consonant group that can't be split",
+                BoundaryRule::TwoConsonants => "two consonants between vowels are split",
+                BoundaryRule::ConsonantCluster => {
+                    "of three or more consonants, the last two go with the next vowel"
+                }
+                BoundaryRule::YBeforeVowel => "'y' before a vowel is a consonant",
+                BoundaryRule::YAsVowel => "'y' after a consonant acts as a vowel",
+            },
+            Language::Spanish => match self {
+                BoundaryRule::Hiatus => "dos vocales en hiato van en sílabas distintas",
+                BoundaryRule::WeakVowelBetweenVowels => {
+                    "una vocal débil entre dos vocales se une a la siguiente"
+                }
+                BoundaryRule::TooManyVowels => "tres vocales que no forman triptongo",
+                BoundaryRule::HiatusWithH => "dos vocales en hiato con «h» intercalada",
+                BoundaryRule::HBeforeDiphthong => "la «h» inicia sílaba ante un diptongo",
+                BoundaryRule::HBeforeConsonant => "la «h» cierra sílaba ante consonante",
+                BoundaryRule::SingleConsonant => "una consonante entre vocales se une a la segunda",
+                BoundaryRule::ConsonantGroup => "grupo consonántico inseparable",
+                BoundaryRule::TwoConsonants => "dos consonantes entre vocales se separan",
+                BoundaryRule::ConsonantCluster => {
+                    "de tres o más consonantes, las dos últimas van con la vocal siguiente"
+                }
+                BoundaryRule::YBeforeVowel => "la «y» ante vocal es consonante",
+                BoundaryRule::YAsVowel => "la «y» tras consonante funciona como vocal",
+            },
+        }
+    }
+}
+
+/// Rules that keep letters in one syllable where a boundary could go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinRule {
+    /// An 'h' between vowels doesn't break up a diphthong, "prohi-bir",
+    /// "rehu-sar".
+    IntercalatedH,
+}
+
+impl JoinRule {
+    pub fn describe(&self, language: Language) -> &'static str {
+        match language {
+            Language::English => match self {
+                JoinRule::IntercalatedH => "an 'h' between vowels doesn't break the diphthong",
+            },
+            Language::Spanish => match self {
+                JoinRule::IntercalatedH => "la «h» intercalada no impide el diptongo",
+            },
+        }
+    }
+}
+
+/// Rules that pick the stressed syllable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StressRule {
+    /// Words of one syllable.
+    Monosyllable,
+    /// The written accent marks the stress, "ár-bol", "can-ción".
+    WrittenAccent,
+    /// Words ending in a triphthong are stressed on the last syllable,
+    /// "Pa-ra-guay".
+    Triphthong,
+    /// Final 'y' after a vowel counts as a consonant, "es-toy".
+    FinalY,
+    /// Words ending in a consonant other than 'n' or 's' are stressed on the
+    /// last syllable, "re-loj".
+    EndsInConsonant,
+    /// Words ending in a vowel, 'n' or 's' are stressed on the penultimate
+    /// syllable, "ca-sa", "e-xa-men".
+    EndsInVowelNOrS,
+}
+
+impl StressRule {
+    pub fn describe(&self, language: Language) -> &'static str {
+        match language {
+            Language::English => match self {
+                StressRule::Monosyllable => "a single syllable",
+                StressRule::WrittenAccent => "the written accent marks the stress",
+                StressRule::Triphthong => "ends in a triphthong, stressed on the last syllable",
+                StressRule::FinalY => {
+                    "final 'y' counts as a consonant, stressed on the last syllable"
+                }
+                StressRule::EndsInConsonant => {
+                    "ends in a consonant other than 'n' or 's', stressed on the last syllable"
+                }
+                StressRule::EndsInVowelNOrS => {
+                    "ends in a vowel, 'n' or 's', stressed on the penultimate syllable"
+                }
+            },
+            Language::Spanish => match self {
+                StressRule::Monosyllable => "monosílabo",
+                StressRule::WrittenAccent => "la tilde marca la sílaba tónica",
+                StressRule::Triphthong => "termina en triptongo, aguda",
+                StressRule::FinalY => "la «y» final cuenta como consonante, aguda",
+                StressRule::EndsInConsonant => "termina en consonante distinta de «n» o «s», aguda",
+                StressRule::EndsInVowelNOrS => "termina en vocal, «n» o «s», llana",
+            },
+        }
+    }
+}
+
+/// A syllabized word, along with the rules behind it.
+#[derive(Debug, Clone)]
+pub struct Explanation {
+    pub word: Word,
+    /// Rule for each boundary, the first one being between the first and
+    /// second syllables.
+    pub boundaries: Vec<BoundaryRule>,
+    /// Rules that kept letters together, with the index of their syllable.
+    pub joins: Vec<(usize, JoinRule)>,
+    /// Rule that picked `word.stress_index`, `None` if there are no
+    /// syllables.
+    pub stress: Option<StressRule>,
+}
+
+#[derive(Default)]
+struct Recorder {
+    bounds: Vec<Bounds>,
+    rules: Vec<BoundaryRule>,
+    joins: Vec<(usize, JoinRule)>,
+}
+
+impl Sink for Recorder {
+    fn push(&mut self, syllable: Bounds) {
+        self.bounds.push(syllable);
+    }
+
+    fn boundary(&mut self, rule: BoundaryRule) {
+        self.rules.push(rule);
+    }
+
+    fn join(&mut self, rule: JoinRule) {
+        self.joins.push((self.bounds.len(), rule));
+    }
+}
+
+/// Syllabizes a word the same as [`Word::from`], explaining each decision.
+///
+/// # Example
+///
+/// ```
+/// use syllabize_es::explain::{explain, BoundaryRule, Language, StressRule};
+/// let explanation = explain("atleta");
+/// assert_eq!(explanation.word.syllabize("-"), "a-tle-ta");
+/// assert_eq!(
+///     explanation.boundaries,
+///     vec![BoundaryRule::ConsonantGroup, BoundaryRule::SingleConsonant]
+/// );
+/// assert_eq!(explanation.stress, Some(StressRule::EndsInVowelNOrS));
+/// assert_eq!(
+///     BoundaryRule::ConsonantGroup.describe(Language::Spanish),
+///     "grupo consonántico inseparable"
+/// );
+/// ```
+pub fn explain(input: &str) -> Explanation {
+    let (chars, normalized) = composed_chars(input);
+    let mut recorder = Recorder::default();
+    if automaton::split(chars.iter().copied(), &mut recorder).is_err() {
+        return Explanation {
+            word: Word::from(input),
+            boundaries: vec![],
+            joins: vec![],
+            stress: None,
+        };
+    }
+    let word = Word::new(input, &chars, &recorder.bounds, normalized);
+    let stress = stress_with_rule(&word.syllables).map(|(_, rule)| rule);
+    Explanation {
+        word,
+        boundaries: recorder.rules,
+        joins: recorder.joins,
+        stress,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_boundaries() {
+        let cases = [
+            ("leer", vec![BoundaryRule::Hiatus]),
+            ("búho", vec![BoundaryRule::HiatusWithH]),
+            (
+                "ahuecar",
+                vec![
+                    BoundaryRule::HBeforeDiphthong,
+                    BoundaryRule::SingleConsonant,
+                ],
+            ),
+            (
+                "subrayar",
+                vec![BoundaryRule::ConsonantGroup, BoundaryRule::SingleConsonant],
+            ),
+            ("alto", vec![BoundaryRule::TwoConsonants]),
+            ("construir", vec![BoundaryRule::ConsonantCluster]),
+            (
+                "cónyuge",
+                vec![BoundaryRule::YBeforeVowel, BoundaryRule::SingleConsonant],
+            ),
+            ("pony", vec![BoundaryRule::YAsVowel]),
+            ("prohibir", vec![BoundaryRule::SingleConsonant]),
+            ("rehusar", vec![BoundaryRule::SingleConsonant]),
+        ];
+        for (s, rules) in cases {
+            let explanation = explain(s);
+            assert_eq!(explanation.boundaries, rules, "{}", s);
+            assert_eq!(
+                explanation.boundaries.len() + 1,
+                explanation.word.syllables.len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_joins() {
+        for (s, syllables, joins) in [
+            ("prohibir", "prohi-bir", vec![(0, JoinRule::IntercalatedH)]),
+            ("rehusar", "rehu-sar", vec![(0, JoinRule::IntercalatedH)]),
+            ("cohibido", "cohi-bi-do", vec![(0, JoinRule::IntercalatedH)]),
+            ("búho", "bú-ho", vec![]),
+            ("ahuecar", "a-hue-car", vec![]),
+            ("casa", "ca-sa", vec![]),
+        ] {
+            let explanation = explain(s);
+            assert_eq!(explanation.word.syllabize("-"), syllables);
+            assert_eq!(explanation.joins, joins, "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_stress() {
+        let cases = [
+            ("sol", Some(StressRule::Monosyllable)),
+            ("canción", Some(StressRule::WrittenAccent)),
+            ("Paraguay", Some(StressRule::Triphthong)),
+            ("estoy", Some(StressRule::FinalY)),
+            ("reloj", Some(StressRule::EndsInConsonant)),
+            ("examen", Some(StressRule::EndsInVowelNOrS)),
+            ("", None),
+        ];
+        for (s, rule) in cases {
+            let explanation = explain(s);
+            assert_eq!(explanation.stress, rule, "{}", s);
+            let word = Word::from(s);
+            assert_eq!(explanation.word.syllables, word.syllables);
+            assert_eq!(explanation.word.stress_index, word.stress_index);
+        }
+    }
+}
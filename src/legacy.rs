@@ -0,0 +1,276 @@
+//! The hand-written state machine that predates [`crate::automaton`], kept
+//! to check the automaton against it.
+
+use crate::char_util::{can_form_hiatus, can_form_triphthong, IsVowel};
+use crate::str_util::is_consonant_pair;
+use crate::{Bounds, ParseError, Result};
+
+#[derive(PartialEq)]
+enum Position {
+    None,
+    Onset,   // Dos, ataque
+    Nucleus, // dOs, nucleo
+    Coda,    // doS
+}
+
+/// Splits a word into syllables, returning the number of characters in the
+/// parts of each syllable.
+///
+/// Syllables cover all characters, in order, so slicing the input by the
+/// accumulated lengths gives the syllables back.
+pub(crate) fn syllable_bounds(chars: &[char]) -> Result<Vec<Bounds>> {
+    if chars.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let word_len = chars.len();
+
+    if word_len == 1 {
+        if matches!(chars[0], 'q' | 'g' | 'Q' | 'G') {
+            return Err(ParseError::IncompleteOnset { offset: 0 });
+        }
+        return Ok(vec![Bounds {
+            onset: 0,
+            nucleus: 1,
+            coda: 0,
+        }]);
+    }
+
+    // Officially the longest word is 12 syllables, here we give some leeway
+    // shaves of 100ns
+    let mut syllables: Vec<Bounds> = Vec::with_capacity(32);
+
+    let mut index = 0;
+    let mut position = Position::None;
+    // Index of the first character of the current syllable.
+    let mut start = 0;
+    let mut syllable = Bounds::default();
+
+    loop {
+        let curr_char = chars[index];
+        let lower = curr_char.to_ascii_lowercase();
+        if !curr_char.is_vowel() {
+            if position == Position::None || position == Position::Onset {
+                if lower == 'y' {
+                    if syllable.onset == 0 {
+                        syllable.onset += 1;
+                        position = Position::Onset;
+                    } else {
+                        syllable.nucleus += 1;
+                        position = Position::Nucleus
+                    }
+                } else if lower == 'q' || lower == 'g' {
+                    syllable.onset += 1;
+                    position = Position::Onset;
+                    if word_len <= index + 1 {
+                        return Err(ParseError::IncompleteOnset { offset: index });
+                    }
+                    let next_char = chars[index + 1];
+                    if next_char.eq_ignore_ascii_case(&'u') {
+                        if word_len <= index + 2 {
+                            return Err(ParseError::IncompleteOnset { offset: index });
+                        }
+                        // Silent 'u' in "que", "qui", "gue", "gui", otherwise
+                        // it's a vowel and gets handled on the next round.
+                        let after_next_char = chars[index + 2];
+                        if matches!(
+                            after_next_char.to_ascii_lowercase(),
+                            'i' | 'e' | 'í' | 'é' | 'Í' | 'É'
+                        ) {
+                            syllable.onset += 1;
+                            syllable.nucleus += 1;
+                            position = Position::Nucleus;
+                            index += 2;
+                        }
+                    }
+                } else {
+                    syllable.onset += 1;
+                    position = Position::Onset;
+                }
+            } else if position == Position::Nucleus {
+                if lower == 'y'
+                    && (index == word_len - 1
+                        || (index + 1 < word_len && !chars[index + 1].is_vowel()))
+                {
+                    syllable.nucleus += 1;
+                } else if lower == 'h' {
+                    index += 1;
+                    if index == chars.len() {
+                        syllable.coda += 1;
+                        position = Position::Coda;
+                    } else {
+                        let next_char = chars[index];
+                        if next_char.is_vowel() {
+                            if syllable.nucleus == 1
+                                && can_form_hiatus(chars[start + syllable.onset], next_char)
+                            {
+                                start = push_syllable(&mut syllables, syllable, start);
+                                syllable = Bounds {
+                                    onset: 1,
+                                    nucleus: 1,
+                                    coda: 0,
+                                };
+                                position = Position::Nucleus;
+                            } else {
+                                index += 1;
+                                if index == chars.len() {
+                                    syllable.nucleus += 2;
+                                    position = Position::Coda;
+                                } else {
+                                    let after_next_char = chars[index];
+                                    if after_next_char.is_vowel() {
+                                        start = push_syllable(&mut syllables, syllable, start);
+                                        syllable = Bounds {
+                                            onset: 1,
+                                            nucleus: 2,
+                                            coda: 0,
+                                        };
+                                        position = Position::Nucleus;
+                                    } else {
+                                        syllable.nucleus += 2;
+                                        syllable.coda += 1;
+                                        position = Position::Coda;
+                                    }
+                                }
+                            }
+                        } else {
+                            syllable.coda += 1;
+                            start = push_syllable(&mut syllables, syllable, start);
+                            syllable = Bounds {
+                                onset: 1,
+                                nucleus: 0,
+                                coda: 0,
+                            };
+                            position = Position::Onset;
+                        }
+                    }
+                } else {
+                    syllable.coda += 1;
+                    position = Position::Coda;
+                }
+            } else if position == Position::Coda {
+                if lower == 'y' {
+                    if syllable.coda == 1 {
+                        if index + 1 < word_len && chars[index + 1].is_vowel() {
+                            start = push_syllable(&mut syllables, syllable, start);
+                            syllable = Bounds {
+                                onset: 1,
+                                nucleus: 0,
+                                coda: 0,
+                            };
+                            position = Position::Onset;
+                        } else {
+                            syllable.coda = 0;
+                            start = push_syllable(&mut syllables, syllable, start);
+                            syllable = Bounds {
+                                onset: 1,
+                                nucleus: 1,
+                                coda: 0,
+                            };
+                            position = Position::Nucleus;
+                        }
+                    } else if syllable.coda == 2 {
+                        let coda_start = start + syllable.onset + syllable.nucleus;
+                        let onset = if is_consonant_pair(chars[coda_start], chars[coda_start + 1]) {
+                            2
+                        } else {
+                            1
+                        };
+                        syllable.coda -= onset;
+                        start = push_syllable(&mut syllables, syllable, start);
+                        syllable = Bounds {
+                            onset,
+                            nucleus: 1,
+                            coda: 0,
+                        };
+                        position = Position::Nucleus;
+                    } else {
+                        syllable.coda += 1;
+                    }
+                } else {
+                    syllable.coda += 1;
+                }
+            }
+        } else if position == Position::None || position == Position::Onset {
+            position = Position::Nucleus;
+            syllable.nucleus += 1;
+        } else if position == Position::Nucleus {
+            let nucleus_start = start + syllable.onset;
+            if syllable.nucleus == 1 {
+                if can_form_hiatus(chars[nucleus_start], curr_char) {
+                    start = push_syllable(&mut syllables, syllable, start);
+                    syllable = Bounds {
+                        onset: 0,
+                        nucleus: 1,
+                        coda: 0,
+                    };
+                } else {
+                    syllable.nucleus += 1;
+                }
+            } else if syllable.nucleus == 2 {
+                if can_form_triphthong(chars[nucleus_start], chars[nucleus_start + 1], curr_char) {
+                    syllable.nucleus += 1;
+                } else if chars[nucleus_start + 1].is_weak_vowel() {
+                    syllable.nucleus = 1;
+                    start = push_syllable(&mut syllables, syllable, start);
+                    syllable = Bounds {
+                        onset: 0,
+                        nucleus: 2,
+                        coda: 0,
+                    }
+                } else {
+                    start = push_syllable(&mut syllables, syllable, start);
+                    syllable = Bounds {
+                        onset: 0,
+                        nucleus: 1,
+                        coda: 0,
+                    }
+                }
+                position = Position::Nucleus;
+            } else {
+                // No nucleus holds more than three vowels.
+                start = push_syllable(&mut syllables, syllable, start);
+                syllable = Bounds {
+                    onset: 0,
+                    nucleus: 1,
+                    coda: 0,
+                };
+            }
+        } else if position == Position::Coda {
+            let coda_start = start + syllable.onset + syllable.nucleus;
+            let onset = match syllable.coda {
+                1 => 1,
+                2 => {
+                    if is_consonant_pair(chars[coda_start], chars[coda_start + 1]) {
+                        2
+                    } else {
+                        1
+                    }
+                }
+                3 => 2,
+                _ => syllable.coda - 2,
+            };
+            syllable.coda -= onset;
+            start = push_syllable(&mut syllables, syllable, start);
+            syllable = Bounds {
+                onset,
+                nucleus: 1,
+                coda: 0,
+            };
+            position = Position::Nucleus;
+        }
+
+        index += 1;
+        if index > word_len - 1 {
+            syllables.push(syllable);
+            break;
+        }
+    }
+    Ok(syllables)
+}
+
+/// Pushes a finished syllable, returning where the next one starts.
+fn push_syllable(syllables: &mut Vec<Bounds>, syllable: Bounds, start: usize) -> usize {
+    syllables.push(syllable);
+    start + syllable.len()
+}
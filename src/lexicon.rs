@@ -0,0 +1,319 @@
+//! Words whose syllables or stress don't follow the rules, such as brand
+//! names, loanwords and proper nouns.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::rules::fixed_bounds;
+use crate::{composed_chars, Bounds};
+
+/// A word with explicit syllables and stress.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exception {
+    /// Syllables as written, "Face", "book".
+    pub syllables: Vec<String>,
+    pub stress_index: usize,
+    /// How the word is pronounced, or anything else worth knowing about it.
+    pub note: Option<String>,
+}
+
+impl Exception {
+    /// The word without hyphens.
+    pub fn spelling(&self) -> String {
+        self.syllables.concat()
+    }
+}
+
+/// Exceptions consulted by [`Word::parse_with`](crate::Word::parse_with)
+/// before any rule.
+///
+/// In text, each exception takes a line with the syllables separated by
+/// hyphens, the index of the stressed syllable and an optional note. The
+/// words of a name are joined with '_', "Nue-va_Jer-sey". Lines starting
+/// with '#' are ignored. Later lines replace earlier ones
+/// for the same word.
+///
+/// ```text
+/// # Loanwords
+/// Face-book 0 /féisbuk/
+/// Jer-sey 0
+/// Nue-va_Jer-sey 3
+/// Mé-xi-co 0 'x' sounds like 'j'
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use syllabize_es::lexicon::Lexicon;
+/// use syllabize_es::rules::SyllabizeRules;
+/// use syllabize_es::Word;
+/// let mut rules = SyllabizeRules::default();
+/// rules.lexicon = "Face-book 0 /féisbuk/".parse::<Lexicon>().unwrap();
+/// let word = Word::parse_with("facebook", &rules).unwrap();
+/// assert_eq!(word.syllabize("-"), "face-book");
+/// assert_eq!(word.stress_index, 0);
+/// let exception = rules.lexicon.get("Facebook").unwrap();
+/// assert_eq!(exception.note.as_deref(), Some("/féisbuk/"));
+/// assert_eq!(rules.lexicon.to_string(), "Face-book 0 /féisbuk/\n");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Lexicon {
+    /// Exceptions in the order they were added, with the bounds of their
+    /// syllables.
+    entries: Vec<(Exception, Vec<Bounds>)>,
+    /// Positions in `entries` by lowercase spelling.
+    index: HashMap<Vec<char>, usize>,
+}
+
+/// Reasons why a lexicon can't be read from text.
+///
+/// Lines are counted from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LexiconError {
+    /// A syllable of the word has no vowel.
+    InvalidSyllables { line: usize },
+    /// The stress index is missing, or past the last syllable.
+    InvalidStress { line: usize },
+}
+
+impl fmt::Display for LexiconError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LexiconError::InvalidSyllables { line } => {
+                write!(f, "invalid syllables on line {}", line)
+            }
+            LexiconError::InvalidStress { line } => write!(f, "invalid stress on line {}", line),
+        }
+    }
+}
+
+impl std::error::Error for LexiconError {}
+
+impl FromStr for Lexicon {
+    type Err = LexiconError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lexicon = Lexicon::default();
+        for (i, line) in s.lines().enumerate() {
+            let line_no = i + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.splitn(3, char::is_whitespace);
+            let split = fields.next().unwrap_or_default();
+            let syllables = split
+                .split('-')
+                .map(|s| s.replace('_', " "))
+                .collect::<Vec<String>>();
+            let stress_index = fields
+                .next()
+                .and_then(|s| s.parse::<usize>().ok())
+                .filter(|&i| i < syllables.len())
+                .ok_or(LexiconError::InvalidStress { line: line_no })?;
+            let note = fields
+                .next()
+                .map(str::trim)
+                .filter(|n| !n.is_empty())
+                .map(String::from);
+            let exception = Exception {
+                syllables,
+                stress_index,
+                note,
+            };
+            if !lexicon.insert(exception) {
+                return Err(LexiconError::InvalidSyllables { line: line_no });
+            }
+        }
+        Ok(lexicon)
+    }
+}
+
+impl fmt::Display for Lexicon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for entry in self.iter() {
+            let split = entry.syllables.join("-").replace(' ', "_");
+            write!(f, "{} {}", split, entry.stress_index)?;
+            if let Some(note) = &entry.note {
+                write!(f, " {}", note)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Lexicon {
+    /// Adds `exception`, replacing the one for the same word, regardless of
+    /// case.
+    ///
+    /// The note is trimmed, and dropped if empty. Returns false, leaving the
+    /// lexicon as it was, if a syllable has no vowel or can't be written in
+    /// text, the stress is past the last syllable or the note spans more than
+    /// one line.
+    pub fn insert(&mut self, mut exception: Exception) -> bool {
+        exception.note = exception
+            .note
+            .as_deref()
+            .map(str::trim)
+            .filter(|n| !n.is_empty())
+            .map(String::from);
+        if !exception.syllables.iter().all(|s| is_writable(s)) {
+            return false;
+        }
+        let Some(bounds) = fixed_bounds(&exception.syllables.join("-")) else {
+            return false;
+        };
+        let valid_note = exception
+            .note
+            .as_ref()
+            .is_none_or(|n| !n.contains(['\n', '\r']));
+        if exception.stress_index >= bounds.len() || !valid_note {
+            return false;
+        }
+        let key = lowercase(&composed_chars(&exception.spelling()).0).into_owned();
+        match self.index.get(&key) {
+            Some(&i) => self.entries[i] = (exception, bounds),
+            None => {
+                self.index.insert(key, self.entries.len());
+                self.entries.push((exception, bounds));
+            }
+        }
+        true
+    }
+
+    /// The exception for `word`, regardless of case.
+    pub fn get(&self, word: &str) -> Option<&Exception> {
+        self.find(&composed_chars(word).0).map(|(entry, _)| entry)
+    }
+
+    /// The exceptions in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = &Exception> {
+        self.entries.iter().map(|(entry, _)| entry)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The exception for the composed characters of a word, with the bounds
+    /// of its syllables.
+    pub(crate) fn find(&self, chars: &[char]) -> Option<(&Exception, &[Bounds])> {
+        let &i = self.index.get(lowercase(chars).as_ref())?;
+        let (entry, bounds) = &self.entries[i];
+        Some((entry, bounds))
+    }
+}
+
+/// Returns true if `syllable` reads back the same from text: words
+/// separated by single spaces, with no hyphens or underscores, and not
+/// starting a comment.
+fn is_writable(syllable: &str) -> bool {
+    !syllable.starts_with('#')
+        && !syllable.contains(['-', '_'])
+        && syllable
+            .split(' ')
+            .all(|w| !w.is_empty() && !w.contains(char::is_whitespace))
+}
+
+/// `chars` in lowercase, one for one so the bounds still add up, borrowed
+/// if they already are.
+fn lowercase(chars: &[char]) -> Cow<'_, [char]> {
+    let lower = |c: char| c.to_lowercase().next().unwrap_or(c);
+    if chars.iter().all(|&c| lower(c) == c) {
+        return Cow::Borrowed(chars);
+    }
+    Cow::Owned(chars.iter().map(|&c| lower(c)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        let lexicon = "# comment\n\nJer-sey 0\nMé-xi-co 0  'x' as 'j' \n"
+            .parse::<Lexicon>()
+            .unwrap();
+        assert_eq!(lexicon.len(), 2);
+        assert_eq!(lexicon.get("jersey").unwrap().note, None);
+        assert_eq!(
+            lexicon.get("MÉXICO").unwrap().note.as_deref(),
+            Some("'x' as 'j'")
+        );
+        assert_eq!(
+            "Jer-sey".parse::<Lexicon>(),
+            Err(LexiconError::InvalidStress { line: 1 })
+        );
+        assert_eq!(
+            "Jer-sey 2".parse::<Lexicon>(),
+            Err(LexiconError::InvalidStress { line: 1 })
+        );
+        assert_eq!(
+            "\nJ-er-sey 0".parse::<Lexicon>(),
+            Err(LexiconError::InvalidSyllables { line: 2 })
+        );
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut lexicon = "Jer-sey 0 old".parse::<Lexicon>().unwrap();
+        let exception = |syllables: &[&str], stress_index, note: Option<&str>| Exception {
+            syllables: syllables.iter().map(|s| s.to_string()).collect(),
+            stress_index,
+            note: note.map(String::from),
+        };
+        assert!(lexicon.insert(exception(&["JER", "SEY"], 1, Some(" new "))));
+        assert_eq!(lexicon.len(), 1);
+        let entry = lexicon.get("jersey").unwrap();
+        assert_eq!(
+            (entry.stress_index, entry.note.as_deref()),
+            (1, Some("new"))
+        );
+        assert!(lexicon.insert(exception(&["Wind", "sor"], 0, Some(""))));
+        assert_eq!(lexicon.get("windsor").unwrap().note, None);
+        assert_eq!(
+            lexicon
+                .find(&['w', 'i', 'n', 'd', 's', 'o', 'r'])
+                .unwrap()
+                .1
+                .len(),
+            2
+        );
+        assert!(!lexicon.insert(exception(&["Face", "book"], 0, Some("a\nb"))));
+        assert!(!lexicon.insert(exception(&["Face", "book"], 2, None)));
+        assert!(!lexicon.insert(exception(&["F", "acebook"], 0, None)));
+        assert_eq!(lexicon.get("Facebook"), None);
+        assert!(lexicon.insert(exception(&["bi\u{307}", "a"], 0, None)));
+        assert_eq!(lexicon.find(&['b', 'İ', 'a']), None);
+        assert!(lexicon.find(&['B', 'I', '\u{307}', 'A']).is_some());
+        assert_eq!(lexicon.len(), 3);
+        for syllables in [
+            &["Nue", "va  Jer", "sey"][..],
+            &["Nue", "va ", "Jer", "sey"],
+            &["Nue", "va\tJer", "sey"],
+            &["Nue", "va_Jer", "sey"],
+            &["#Jer", "sey"],
+        ] {
+            assert!(
+                !lexicon.insert(exception(syllables, 0, None)),
+                "{:?}",
+                syllables
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let text = "Jer-sey 0\nNue-va_Jer-sey 2\nMé-xi-co 0 'x' as 'j'\n";
+        let lexicon = text.parse::<Lexicon>().unwrap();
+        assert_eq!(lexicon.to_string(), text);
+        assert_eq!(lexicon.to_string().parse::<Lexicon>(), Ok(lexicon));
+    }
+}
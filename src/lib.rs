@@ -5,33 +5,107 @@
 
 extern crate test;
 
-use char_util::can_form_hiatus;
+use std::borrow::Cow;
 use std::fmt;
 use std::fmt::Display;
-use std::usize;
+use std::str::FromStr;
 use str_util::is_both_b_or_v;
 use str_util::is_both_s_or_z;
 use str_util::loose_match;
 
+pub mod accent;
 pub mod char_util;
 pub mod str_util;
 pub mod syllable;
+pub mod word_ref;
 
-use crate::char_util::can_form_triphthong;
+mod automaton;
+mod count;
+pub mod diacritic;
+pub mod enclitic;
+pub mod explain;
+#[cfg(test)]
+mod legacy;
+pub mod lexicon;
+pub mod lint;
+pub mod loanword;
+pub mod plural;
+pub mod prefix;
+pub mod reform;
+pub mod rules;
+
+pub use count::{count_and_stress, count_syllables, stress_position};
+
+use crate::accent::Accent;
+use crate::accent::AccentType;
+use crate::accent::StrippedWord;
+use crate::accent::TildeCheck;
 use crate::char_util::combo_type;
+use crate::char_util::is_letter;
 use crate::char_util::ComboType;
 use crate::char_util::IsVowel;
-use crate::str_util::is_consonant_group;
+use crate::explain::StressRule;
+use crate::reform::Orthography;
+use crate::rules::SyllabizeRules;
+use crate::str_util::composed;
+use crate::syllable::Parts;
+use crate::syllable::Span;
 use crate::syllable::Syllable;
+use crate::syllable::SyllableSpan;
 
-type Result<T> = std::result::Result<T, InvalidWord>;
+type Result<T> = std::result::Result<T, ParseError>;
 
-#[derive(Debug, Clone)]
-struct InvalidWord;
+/// Reasons why a string can't be parsed into a [`Word`].
+///
+/// Offsets are counted in characters, not bytes, from the start of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The word ends right after "q" or "gu", which must be followed by a vowel.
+    IncompleteOnset { offset: usize },
+    /// A syllable has no vowel to act as its nucleus, e.g. "nn".
+    NoNucleus { offset: usize },
+    /// The character isn't a letter of the Spanish alphabet.
+    UnsupportedCharacter { character: char, offset: usize },
+}
 
-impl fmt::Display for InvalidWord {
+impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "invalid word")
+        match self {
+            ParseError::Empty => write!(f, "empty word"),
+            ParseError::IncompleteOnset { offset } => {
+                write!(f, "word ends after an incomplete onset at {}", offset)
+            }
+            ParseError::NoNucleus { offset } => {
+                write!(f, "syllable without a vowel at {}", offset)
+            }
+            ParseError::UnsupportedCharacter { character, offset } => {
+                write!(f, "unsupported character {:?} at {}", character, offset)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl ParseError {
+    /// Maps the offset of the error, e.g. from the normalized input back to
+    /// the original one.
+    fn map_offset(self, f: impl Fn(usize) -> usize) -> Self {
+        match self {
+            ParseError::Empty => ParseError::Empty,
+            ParseError::IncompleteOnset { offset } => {
+                ParseError::IncompleteOnset { offset: f(offset) }
+            }
+            ParseError::NoNucleus { offset } => ParseError::NoNucleus { offset: f(offset) },
+            ParseError::UnsupportedCharacter { character, offset } => {
+                ParseError::UnsupportedCharacter {
+                    character,
+                    offset: f(offset),
+                }
+            }
+        }
     }
 }
 
@@ -48,18 +122,96 @@ pub enum StressType {
     Superproparoxytone,
 }
 
-// TODO: Another stress type
-// pub enum StressType {
-//     Prosodic,  // Acento prosódico
-//     Orthographic,  // Acento ortográfico
-// }
+/// Stressed syllables of a word. Adverbs in -mente and hyphenated compounds
+/// keep the stress of each part, "rá-pi-da-men-te", "te-ó-ri-co-prác-ti-co".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stresses {
+    /// Index of the main stressed syllable, the one of the last part.
+    pub primary: usize,
+    /// Indices of the other stressed syllables, in order.
+    pub secondary: Vec<usize>,
+}
+
+/// How words are split into syllables.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SplitMode {
+    /// By sound alone, "su-bra-yar", "de-sa-mor".
+    #[default]
+    Phonological,
+    /// Keeping the prefixes in [`prefix::PREFIXED`] apart, "sub-ra-yar",
+    /// "des-a-mor".
+    Morphological,
+}
+
+/// Regional differences in how consonants are split.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Dialect {
+    /// Keeps "tl" together, as in Mexico and most of America, "a-tle-ta",
+    /// "Tlal-pan".
+    #[default]
+    American,
+    /// Splits "tl" between vowels, as in Spain, "at-le-ta", "at-lán-ti-co".
+    /// At the start of a word it stays together, "Tlal-pan".
+    Peninsular,
+}
+
+/// Consonant groups that [`Dialect::Peninsular`] splits.
+const PENINSULAR_SPLITS: &[(char, char)] = &[('t', 'l')];
+
+/// Options for [`Word::parse_with_options`].
+///
+/// More options may be added, so build them from the default.
+///
+/// # Example
+///
+/// ```
+/// use syllabize_es::{Dialect, SyllabizeOptions};
+/// let options = SyllabizeOptions::default().with_dialect(Dialect::Peninsular);
+/// assert_eq!(options.dialect, Dialect::Peninsular);
+/// assert!(!options.loanwords);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SyllabizeOptions {
+    pub mode: SplitMode,
+    pub dialect: Dialect,
+    /// Keeps the letter groups of loanwords together, "ho-ckey", and reads
+    /// 'w' as a semivowel after a vowel, "show". See [`loanword`].
+    pub loanwords: bool,
+}
+
+impl SyllabizeOptions {
+    /// The options with [`SyllabizeOptions::mode`] set.
+    pub fn with_mode(self, mode: SplitMode) -> Self {
+        SyllabizeOptions { mode, ..self }
+    }
+
+    /// The options with [`SyllabizeOptions::dialect`] set.
+    pub fn with_dialect(self, dialect: Dialect) -> Self {
+        SyllabizeOptions { dialect, ..self }
+    }
+
+    /// The options with [`SyllabizeOptions::loanwords`] set.
+    pub fn with_loanwords(self, loanwords: bool) -> Self {
+        SyllabizeOptions { loanwords, ..self }
+    }
+}
+
+/// Words ending in "mente" that aren't adverbs.
+const NOT_MENTE_ADVERBS: &[&str] = &["mente", "demente", "clemente", "inclemente", "vehemente"];
 
-#[derive(PartialEq)]
-enum Position {
-    None,
-    Onset,   // Dos, ataque
-    Nucleus, // dOs, nucleo
-    Coda,    // doS
+/// Number of characters in each part of a syllable.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct Bounds {
+    pub(crate) onset: usize,
+    pub(crate) nucleus: usize,
+    pub(crate) coda: usize,
+}
+
+impl Bounds {
+    pub(crate) fn len(&self) -> usize {
+        self.onset + self.nucleus + self.coda
+    }
 }
 
 #[derive(PartialEq, Debug)]
@@ -79,17 +231,21 @@ pub struct Hiatus {
     pub syllable_index: usize,
     pub composite: String,
     pub kind: HiatusType,
+    /// From the first vowel to the second, including an intercalated 'h'.
+    pub span: Span,
 }
 
 pub struct Diphthong {
     pub syllable_index: usize,
     pub composite: String,
     pub kind: DiphthongType,
+    pub span: Span,
 }
 
 pub struct Triphthong {
     pub syllable_index: usize,
     pub composite: String,
+    pub span: Span,
 }
 
 pub struct VowelCombos {
@@ -111,15 +267,19 @@ pub fn equal_onset(a: &Syllable, b: &Syllable, opt: &RhymeOptions) -> bool {
     }
     if opt.seseo {
         if a.onset == "c" && b.onset == "s" {
-            let a_first_nucleus = a.nucleus.chars().next().unwrap();
-            if a_first_nucleus.is_soft_c_trigger()
+            if a.nucleus
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_soft_c_trigger())
                 && loose_match(a.nucleus.as_str(), b.nucleus.as_str())
             {
                 return true;
             }
         } else if a.onset == "s" && b.onset == "c" {
-            let b_first_nucleus = b.nucleus.chars().next().unwrap();
-            if b_first_nucleus.is_soft_c_trigger()
+            if b.nucleus
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_soft_c_trigger())
                 && loose_match(a.nucleus.as_str(), b.nucleus.as_str())
             {
                 return true;
@@ -128,15 +288,11 @@ pub fn equal_onset(a: &Syllable, b: &Syllable, opt: &RhymeOptions) -> bool {
             return true;
         }
     }
-    if opt.yeismo {
-        if a.onset == "y" && b.onset == "ll" || a.onset == "ll" && b.onset == "y" {
-            return true;
-        }
+    if opt.yeismo && (a.onset == "y" && b.onset == "ll" || a.onset == "ll" && b.onset == "y") {
+        return true;
     }
-    if opt.b_equals_v {
-        if is_both_b_or_v(a.onset.as_str(), b.onset.as_str()) {
-            return true;
-        }
+    if opt.b_equals_v && is_both_b_or_v(a.onset.as_str(), b.onset.as_str()) {
+        return true;
     }
 
     false
@@ -147,14 +303,199 @@ pub fn equal_onset(a: &Syllable, b: &Syllable, opt: &RhymeOptions) -> bool {
 pub struct Word {
     pub syllables: Vec<Syllable>,
     pub stress_index: usize,
+    /// True if decomposed accents in the input, e.g. "o\u{301}" from a PDF,
+    /// were composed before syllabizing.
+    pub normalized: bool,
+    /// Location of each syllable in the original input, parallel to
+    /// `syllables`.
+    pub spans: Vec<SyllableSpan>,
+    /// The mode that produced the syllables.
+    pub mode: SplitMode,
+    /// The dialect that produced the syllables.
+    pub dialect: Dialect,
+    /// True if the syllables were split as a loanword.
+    pub loanwords: bool,
+    /// Letters and letter groups foreign to Spanish, "w", "sh", found when
+    /// splitting as a loanword.
+    pub foreign_graphemes: Vec<Span>,
 }
 
 impl Word {
+    pub(crate) fn new(input: &str, chars: &[char], bounds: &[Bounds], normalized: bool) -> Word {
+        let syllables = to_syllables(chars, bounds);
+        let stress_index = identify_stress(&syllables);
+        let spans = syllable_spans(bounds, &original_offsets(input));
+        Word {
+            syllables,
+            stress_index,
+            normalized,
+            spans,
+            mode: SplitMode::Phonological,
+            dialect: Dialect::American,
+            loanwords: false,
+            foreign_graphemes: vec![],
+        }
+    }
+
+    /// Parses a word like [`str::parse`], splitting it as `options` say.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syllabize_es::{SplitMode, SyllabizeOptions, Word};
+    /// let options = SyllabizeOptions::default().with_mode(SplitMode::Morphological);
+    /// let word = Word::parse_with_options("subrayar", options).unwrap();
+    /// assert_eq!(word.syllabize("-"), "sub-ra-yar");
+    /// assert_eq!(word.mode, SplitMode::Morphological);
+    /// assert_eq!(Word::from("subrayar").syllabize("-"), "su-bra-yar");
+    /// ```
+    pub fn parse_with_options(input: &str, options: SyllabizeOptions) -> Result<Word> {
+        let (chars, normalized) = composed_chars(input);
+        let bounds = checked_bounds(input, &chars)?;
+        Ok(Word::with_bounds(
+            input, &chars, bounds, normalized, options,
+        ))
+    }
+
+    /// Parses a word like [`Word::parse_with_options`], with the lexicon,
+    /// consonant groups and fixed words of `rules`.
+    ///
+    /// Words in the lexicon keep their syllables and stress as written, and
+    /// fixed words their syllables. Otherwise `rules.options`
+    /// apply after the consonant groups, so [`Dialect::Peninsular`] splits
+    /// "tl" even if it's one of `rules.clusters`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syllabize_es::rules::SyllabizeRules;
+    /// use syllabize_es::Word;
+    /// let mut rules = SyllabizeRules::default();
+    /// rules.clusters.retain(|&pair| pair != ('t', 'l'));
+    /// assert_eq!(Word::parse_with("atleta", &rules).unwrap().syllabize("-"), "at-le-ta");
+    /// ```
+    pub fn parse_with(input: &str, rules: &SyllabizeRules) -> Result<Word> {
+        let (chars, normalized) = composed_chars(input);
+        if let Some((bounds, stress_index)) = rules.fixed_split(&chars) {
+            let mut word = Word::new(input, &chars, &bounds, normalized);
+            if let Some(stress_index) = stress_index {
+                word.stress_index = stress_index;
+            }
+            word.set_options(input, rules.options);
+            return Ok(word);
+        }
+        let mut bounds = checked_bounds(input, &chars)?;
+        rules.regroup(&chars, &mut bounds);
+        Ok(Word::with_bounds(
+            input,
+            &chars,
+            bounds,
+            normalized,
+            rules.options,
+        ))
+    }
+
+    /// Lenient version of [`Word::parse_with_options`], invalid words end up
+    /// with no syllables.
+    fn lenient(input: &str, options: SyllabizeOptions) -> Word {
+        let (chars, normalized) = composed_chars(input);
+        match syllable_bounds(&chars) {
+            Ok(bounds) => Word::with_bounds(input, &chars, bounds, normalized, options),
+            Err(_e) => Word {
+                syllables: vec![],
+                stress_index: 0,
+                normalized,
+                spans: vec![],
+                mode: options.mode,
+                dialect: options.dialect,
+                loanwords: options.loanwords,
+                foreign_graphemes: vec![],
+            },
+        }
+    }
+
+    fn with_bounds(
+        input: &str,
+        chars: &[char],
+        mut bounds: Vec<Bounds>,
+        normalized: bool,
+        options: SyllabizeOptions,
+    ) -> Word {
+        if options.dialect == Dialect::Peninsular {
+            split_regional_groups(chars, &mut bounds);
+        }
+        if options.loanwords {
+            loanword::split_loanword(chars, &mut bounds);
+        }
+        if options.mode == SplitMode::Morphological {
+            prefix::split_prefix(chars, &mut bounds);
+        }
+        let mut word = Word::new(input, chars, &bounds, normalized);
+        word.set_options(input, options);
+        word
+    }
+
+    fn set_options(&mut self, input: &str, options: SyllabizeOptions) {
+        self.mode = options.mode;
+        self.dialect = options.dialect;
+        self.loanwords = options.loanwords;
+        if options.loanwords {
+            self.foreign_graphemes = loanword::foreign_graphemes(input);
+        }
+    }
+
+    /// The options that produced the syllables.
+    pub fn options(&self) -> SyllabizeOptions {
+        SyllabizeOptions {
+            mode: self.mode,
+            dialect: self.dialect,
+            loanwords: self.loanwords,
+        }
+    }
+
+    /// Returns the character indices where the word can be broken at the end
+    /// of a line.
+    ///
+    /// Breaks fall between syllables, but never between two vowels, "cre-er"
+    /// stays whole, nor leave a single vowel on either line, "a-tle-ta".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syllabize_es::{Dialect, SyllabizeOptions, Word};
+    /// assert_eq!(Word::from("atleta").hyphenation_points(), [4]);
+    /// let options = SyllabizeOptions::default().with_dialect(Dialect::Peninsular);
+    /// let word = Word::parse_with_options("atleta", options).unwrap();
+    /// assert_eq!(word.hyphenation_points(), [2, 4]);
+    /// ```
+    pub fn hyphenation_points(&self) -> Vec<usize> {
+        let lengths = self
+            .syllables
+            .iter()
+            .map(|s| s.to_string().chars().count())
+            .collect::<Vec<usize>>();
+        let total = lengths.iter().sum::<usize>();
+        let is_lone_vowel = |s: &Syllable| {
+            s.onset.is_empty() && s.coda.is_empty() && s.nucleus.chars().count() == 1
+        };
+        let mut points = vec![];
+        let mut index = 0;
+        for (i, pair) in self.syllables.windows(2).enumerate() {
+            index += lengths[i];
+            let between_vowels = pair[0].coda.is_empty() && pair[1].onset.is_empty();
+            let lone_first = i == 0 && is_lone_vowel(&pair[0]);
+            let lone_last = index + lengths[i + 1] == total && is_lone_vowel(&pair[1]);
+            if !between_vowels && !lone_first && !lone_last {
+                points.push(index);
+            }
+        }
+        points
+    }
+
     pub fn rhyme(&self) -> String {
-        if self.syllables.len() == 0 {
+        let Some(stress_syllable) = self.syllables.get(self.stress_index) else {
             return String::new();
-        }
-        let stress_syllable = &self.syllables[self.stress_index];
+        };
         let mut rhyme = stress_syllable.vowels_since_stress();
         rhyme.push_str(stress_syllable.coda.as_str());
 
@@ -165,8 +506,9 @@ impl Word {
     }
 
     pub fn assonant_rhymes_with(&self, other: &Word) -> bool {
-        let this_syllables = &self.syllables[self.stress_index..self.syllables.len()];
-        let that_syllables = &other.syllables[other.stress_index..other.syllables.len()];
+        let this_syllables = self.syllables_since_stress();
+        let other = other.in_dialect(self.dialect);
+        let that_syllables = other.syllables_since_stress();
         if this_syllables.len() != that_syllables.len() {
             return false;
         }
@@ -202,8 +544,9 @@ impl Word {
             yeismo: true,
             b_equals_v: true,
         });
-        let this_syllables = &self.syllables[self.stress_index..self.syllables.len()];
-        let that_syllables = &other.syllables[other.stress_index..other.syllables.len()];
+        let this_syllables = self.syllables_since_stress();
+        let other = other.in_dialect(self.dialect);
+        let that_syllables = other.syllables_since_stress();
         if this_syllables.len() != that_syllables.len() {
             return false;
         }
@@ -238,53 +581,65 @@ impl Word {
 
     pub fn vowel_combos(&self) -> VowelCombos {
         let syllables = &self.syllables;
+        let nucleus_span = |index: usize| {
+            self.spans
+                .get(index)
+                .map(|s| s.nucleus.clone())
+                .unwrap_or_default()
+        };
         let mut index = 0;
         let mut hiatuses = vec![];
         let mut diphthongs = vec![];
         let mut triphthongs = vec![];
-        while index < syllables.len() {
-            let nucleus_chars: Vec<char> = syllables[index].nucleus.chars().collect();
-            if syllables[index].coda.is_empty()
-                && nucleus_chars.len() == 1
+        // The syllable ends in its nucleus and the next one is a lone vowel,
+        // maybe after a silent 'h'.
+        let before_vowel = |index: usize| {
+            syllables[index].coda.is_empty()
                 && index + 1 < syllables.len()
-                && (syllables[index + 1].onset.is_empty() || syllables[index + 1].onset == "h")
+                && (syllables[index + 1].onset.is_empty()
+                    || syllables[index + 1].onset.eq_ignore_ascii_case("h"))
                 && syllables[index + 1].nucleus.chars().count() == 1
-            {
-                let mut composite = syllables[index].nucleus.clone();
-                composite.push_str(syllables[index + 1].nucleus.as_str());
-                hiatuses.push(Hiatus {
-                    syllable_index: index,
-                    composite,
-                    kind: if syllables[index].has_accented_vowel()
-                        || syllables[index + 1].has_accented_vowel()
-                    {
-                        HiatusType::Accentual
-                    } else {
-                        HiatusType::Simple
-                    },
-                });
+        };
+        let hiatus = |index: usize, first: char, span: Span| Hiatus {
+            syllable_index: index,
+            composite: format!("{}{}", first, syllables[index + 1].nucleus),
+            kind: if first.is_accented_vowel() || syllables[index + 1].has_accented_vowel() {
+                HiatusType::Accentual
+            } else {
+                HiatusType::Simple
+            },
+            span: span.to(&nucleus_span(index + 1)),
+        };
+        while index < syllables.len() {
+            let nucleus_chars: Vec<char> = syllables[index].nucleus.chars().collect();
+            if nucleus_chars.len() == 1 && before_vowel(index) {
+                hiatuses.push(hiatus(index, nucleus_chars[0], nucleus_span(index)));
             } else if nucleus_chars.len() == 2 {
-                let dp_type: DiphthongType = match combo_type(nucleus_chars[0], nucleus_chars[1]) {
-                    ComboType::Diphthong(t) => t,
-                    _ => panic!("Not a diphthong"),
-                };
-                diphthongs.push(Diphthong {
-                    syllable_index: index,
-                    kind: dp_type,
-                    composite: syllables[index].nucleus.clone(),
-                });
-            } else if syllables[index].nucleus.chars().count() == 3 {
+                if let ComboType::Diphthong(kind) = combo_type(nucleus_chars[0], nucleus_chars[1]) {
+                    diphthongs.push(Diphthong {
+                        syllable_index: index,
+                        kind,
+                        composite: syllables[index].nucleus.clone(),
+                        span: nucleus_span(index),
+                    });
+                }
+                // The last vowel of the diphthong meets the next one,
+                // "huí-a", "cons-truí-a".
+                if before_vowel(index) {
+                    let span = nucleus_span(index);
+                    let last = nucleus_chars[1];
+                    let last_span = Span {
+                        bytes: span.bytes.end.saturating_sub(last.len_utf8())..span.bytes.end,
+                        chars: span.chars.end.saturating_sub(1)..span.chars.end,
+                    };
+                    hiatuses.push(hiatus(index, last, last_span));
+                }
+            } else if nucleus_chars.len() == 3 {
                 triphthongs.push(Triphthong {
                     syllable_index: index,
                     composite: syllables[index].nucleus.clone(),
+                    span: nucleus_span(index),
                 });
-            } else if syllables[index].coda.is_empty()
-                && syllables[index].nucleus.chars().count() == 2
-                && index + 1 < syllables.len()
-                && (syllables[index + 1].onset.is_empty() || syllables[index + 1].onset == "h")
-                && syllables[index + 1].nucleus.chars().count() == 1
-            {
-                // ???
             }
             index += 1;
         }
@@ -295,43 +650,220 @@ impl Word {
         }
     }
 
-    pub fn stress(&self) -> StressType {
-        let d = self.syllables.len() - 1 - self.stress_index;
-        match d {
-            0 => StressType::Oxytone,
-            1 => StressType::Paroxytone,
-            2 => StressType::Proparoxytone,
-            3.. => StressType::Superproparoxytone,
-            _ => panic!("Invalid stress count {}", d),
+    /// Returns the type of stress, or `None` if the word has no syllables.
+    pub fn stress(&self) -> Option<StressType> {
+        stress_type(self.syllables.len(), self.stress_index)
+    }
+
+    /// Returns the stressed vowel and whether RAE rules want a tilde on it,
+    /// or `None` if the word has no syllables or no vowel to stress.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syllabize_es::accent::{AccentType, Tilde};
+    /// use syllabize_es::Word;
+    /// let accent = Word::from("camion").accent().unwrap();
+    /// assert_eq!(accent.accent_type, AccentType::Prosodic);
+    /// assert_eq!(accent.vowel, 'a');
+    /// assert_eq!(accent.tilde, Tilde::Forbidden);
+    /// ```
+    pub fn accent(&self) -> Option<Accent> {
+        accent::accent(&self.syllables, self.stress_index)
+    }
+
+    /// Checks the written accent against RAE rules, for the stress on the
+    /// syllable at `stress_index`, or where the word puts it if `None`.
+    ///
+    /// Returns `None` if there's no syllable at the index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syllabize_es::accent::TildeError;
+    /// use syllabize_es::Word;
+    /// let check = Word::from("camion").check_tilde(Some(1)).unwrap();
+    /// assert_eq!(check.error, Some(TildeError::Missing));
+    /// assert_eq!(check.suggestion, "camión");
+    ///
+    /// let check = Word::from("fué").check_tilde(None).unwrap();
+    /// assert_eq!(check.error, Some(TildeError::Superfluous));
+    /// assert_eq!(check.suggestion, "fue");
+    /// ```
+    pub fn check_tilde(&self, stress_index: Option<usize>) -> Option<TildeCheck> {
+        self.check_tilde_in(stress_index, Orthography::Rae2010)
+    }
+
+    /// Same as [`Word::check_tilde`], following the given orthography.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syllabize_es::accent::{Tilde, TildeError};
+    /// use syllabize_es::reform::Orthography;
+    /// use syllabize_es::Word;
+    /// let guion = Word::from("guión");
+    /// let check = guion.check_tilde_in(None, Orthography::Rae1999).unwrap();
+    /// assert_eq!(check.tilde, Tilde::Optional);
+    /// assert_eq!(check.error, None);
+    ///
+    /// let check = guion.check_tilde_in(None, Orthography::Rae2010).unwrap();
+    /// assert_eq!(check.error, Some(TildeError::Superfluous));
+    /// assert_eq!(check.suggestion, "guion");
+    /// ```
+    pub fn check_tilde_in(
+        &self,
+        stress_index: Option<usize>,
+        orthography: Orthography,
+    ) -> Option<TildeCheck> {
+        accent::check_tilde(
+            &self.syllables,
+            stress_index.unwrap_or(self.stress_index),
+            orthography,
+        )
+    }
+
+    /// Respells the word so that it's stressed on the syllable at
+    /// `stress_index`, adding, moving or removing the tilde as RAE rules say.
+    ///
+    /// Returns `None` if there's no syllable at the index. Words with a
+    /// diacritic tilde are returned as they are, since it depends on the
+    /// meaning.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syllabize_es::Word;
+    /// let word = Word::from("termino");
+    /// assert_eq!(word.with_stress(0).unwrap().to_string(), "término");
+    /// assert_eq!(word.with_stress(2).unwrap().to_string(), "terminó");
+    /// assert_eq!(word.with_stress(2).unwrap().stress_index, 2);
+    /// ```
+    pub fn with_stress(&self, stress_index: usize) -> Option<Word> {
+        let spelling = accent::restress(&self.syllables, stress_index)?;
+        Some(Word::lenient(&spelling, self.options()))
+    }
+
+    /// Returns the primary and secondary stresses of the word.
+    ///
+    /// `stress_index` follows the spelling, so it's the first stress of an
+    /// adverb in -mente, where the adjective keeps its tilde. Here the main
+    /// stress goes to the last part, as it's pronounced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syllabize_es::Word;
+    /// let stresses = Word::from("rápidamente").stresses();
+    /// assert_eq!(stresses.primary, 3);
+    /// assert_eq!(stresses.secondary, [0]);
+    ///
+    /// let stresses = Word::from("teórico-práctico").stresses();
+    /// assert_eq!(stresses.primary, 4);
+    /// assert_eq!(stresses.secondary, [1]);
+    ///
+    /// assert!(Word::from("casa").stresses().secondary.is_empty());
+    /// ```
+    pub fn stresses(&self) -> Stresses {
+        let written = self.to_string();
+        let mut stressed = vec![];
+        let mut offset = 0;
+        for part in written.split('-') {
+            let part = Word::from(part);
+            stressed.extend(part_stresses(&part).into_iter().map(|i| offset + i));
+            offset += part.syllables.len();
+        }
+        // Parts that don't syllabize on their own can't be told apart.
+        if offset != self.syllables.len() || stressed.is_empty() {
+            stressed = vec![self.stress_index];
+        }
+        let primary = stressed.pop().unwrap_or(self.stress_index);
+        Stresses {
+            primary,
+            secondary: stressed,
         }
     }
 
+    /// Returns the word without tildes, with what's needed to spell it back,
+    /// or `None` if there's no stressed vowel.
+    ///
+    /// Parsing the bare word would put the stress where the default rules
+    /// say, "termino" for "término", so keep the result to restore it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syllabize_es::Word;
+    /// let stripped = Word::from("país").strip_accents().unwrap();
+    /// assert_eq!(stripped.bare, "pais");
+    /// assert_eq!(stripped.stress_index, 1);
+    /// assert_eq!(stripped.hiatuses, [0]);
+    /// assert_eq!(stripped.restore().unwrap(), "país");
+    /// ```
+    pub fn strip_accents(&self) -> Option<StrippedWord> {
+        accent::strip(self)
+    }
+
+    /// Returns whether the stress is written with a tilde, or `None` if
+    /// there's no stressed vowel.
+    pub fn accent_type(&self) -> Option<AccentType> {
+        self.accent().map(|a| a.accent_type)
+    }
+
+    /// The word split as in `dialect`, so that words from different
+    /// dialects compare the same syllables.
+    fn in_dialect(&self, dialect: Dialect) -> Cow<'_, Word> {
+        if self.dialect == dialect {
+            return Cow::Borrowed(self);
+        }
+        let options = SyllabizeOptions {
+            dialect,
+            ..self.options()
+        };
+        Cow::Owned(Word::lenient(&self.to_string(), options))
+    }
+
+    /// Syllables from the stressed one to the end, empty if `stress_index`
+    /// is out of range.
+    fn syllables_since_stress(&self) -> &[Syllable] {
+        self.syllables.get(self.stress_index..).unwrap_or(&[])
+    }
+
     pub fn syllabize(&self, delimiter: &str) -> String {
-        return self
-            .syllables
+        self.syllables
             .iter()
             .map(|s| s.to_string())
             .collect::<Vec<String>>()
-            .join(delimiter);
+            .join(delimiter)
     }
 }
 
+/// Lenient conversion, invalid words end up with no syllables.
+///
+/// Use [`str::parse`] to find out what went wrong.
 impl From<&str> for Word {
     fn from(item: &str) -> Self {
-        let syllables = to_syllables(item);
-        match syllables {
-            Ok(s) => {
-                let stress_index = identify_stress(&s);
-                Word {
-                    syllables: s,
-                    stress_index,
-                }
-            }
-            Err(_e) => Word {
-                syllables: vec![],
-                stress_index: 0,
-            }
-        }
+        Word::lenient(item, SyllabizeOptions::default())
+    }
+}
+
+impl FromStr for Word {
+    type Err = ParseError;
+
+    /// Parses a word, rejecting input that can't be syllabized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use syllabize_es::{ParseError, Word};
+    /// assert!("queso".parse::<Word>().is_ok());
+    /// assert_eq!("nn".parse::<Word>().unwrap_err(), ParseError::NoNucleus { offset: 0 });
+    /// assert_eq!("gu".parse::<Word>().unwrap_err(), ParseError::IncompleteOnset { offset: 0 });
+    /// ```
+    fn from_str(s: &str) -> Result<Self> {
+        let (chars, normalized) = composed_chars(s);
+        let bounds = checked_bounds(s, &chars)?;
+        Ok(Word::new(s, &chars, &bounds, normalized))
     }
 }
 
@@ -347,346 +879,220 @@ impl Display for Word {
     }
 }
 
+/// Composed characters of the input, and whether any accents were composed.
+pub(crate) fn composed_chars(input: &str) -> (Vec<char>, bool) {
+    let chars = composed(input).map(|(_, c)| c).collect::<Vec<char>>();
+    let normalized = chars.len() != input.chars().count();
+    (chars, normalized)
+}
 
+/// Syllabizes the composed characters of `input`, rejecting invalid words.
+///
+/// Offsets in errors refer to the characters of `input`, not the composed
+/// ones.
+pub(crate) fn checked_bounds(input: &str, chars: &[char]) -> Result<Vec<Bounds>> {
+    validate(chars).map_err(|e| {
+        e.map_offset(|index| {
+            let byte = composed(input).nth(index).map_or(input.len(), |(b, _)| b);
+            input[..byte].chars().count()
+        })
+    })
+}
 
-fn to_syllables(word: &str) -> Result<Vec<Syllable>> {
-    if word.is_empty() {
-        return Ok(vec![]);
+fn validate(chars: &[char]) -> Result<Vec<Bounds>> {
+    if chars.is_empty() {
+        return Err(ParseError::Empty);
     }
-
-    let chars: Vec<char> = word.chars().collect();
-    let word_len = chars.len();
-
-    if word_len == 1 {
-        return Ok(vec![Syllable {
-            onset: "".to_string(),
-            nucleus: chars[0].to_string(),
-            coda: "".to_string(),
-        }]);
+    if let Some(offset) = chars.iter().position(|c| !is_letter(*c)) {
+        return Err(ParseError::UnsupportedCharacter {
+            character: chars[offset],
+            offset,
+        });
+    }
+    let bounds = syllable_bounds(chars)?;
+    let mut start = 0;
+    for b in &bounds {
+        let nucleus = &chars[start + b.onset..start + b.onset + b.nucleus];
+        if !nucleus.iter().any(|c| c.can_be_nucleus()) {
+            return Err(ParseError::NoNucleus { offset: start });
+        }
+        start += b.len();
     }
+    Ok(bounds)
+}
 
-    // Officially the longest word is 12 syllables, here we give some leeway
-    // shaves of 100ns
-    let mut syllables: Vec<Syllable> = Vec::with_capacity(32);
+/// Byte and char offsets in the original input of each composed character,
+/// followed by the offsets of the end of the input.
+fn original_offsets(input: &str) -> Vec<(usize, usize)> {
+    let mut offsets = Vec::with_capacity(input.len() + 1);
+    let mut char_offset = 0;
+    let mut chars = composed(input).peekable();
+    while let Some((byte, _)) = chars.next() {
+        offsets.push((byte, char_offset));
+        let end = chars.peek().map_or(input.len(), |&(b, _)| b);
+        char_offset += input[byte..end].chars().count();
+    }
+    offsets.push((input.len(), char_offset));
+    offsets
+}
 
-    let mut index = 0;
-    let mut position = Position::None;
-    let mut syllable = Syllable {
-        onset: "".to_string(),
-        nucleus: "".to_string(),
-        coda: "".to_string(),
+/// Maps syllables back to the original input, relying on the syllables
+/// covering all of its characters, in order.
+fn syllable_spans(bounds: &[Bounds], offsets: &[(usize, usize)]) -> Vec<SyllableSpan> {
+    let last = offsets.len().saturating_sub(1);
+    let span = |start: usize, end: usize| {
+        let (start, end) = (offsets[start.min(last)], offsets[end.min(last)]);
+        Span {
+            bytes: start.0..end.0,
+            chars: start.1..end.1,
+        }
     };
+    let mut index = 0;
+    bounds
+        .iter()
+        .map(|b| {
+            let onset_end = index + b.onset;
+            let nucleus_end = onset_end + b.nucleus;
+            let coda_end = nucleus_end + b.coda;
+            let syllable_span = SyllableSpan {
+                syllable: span(index, coda_end),
+                onset: span(index, onset_end),
+                nucleus: span(onset_end, nucleus_end),
+                coda: span(nucleus_end, coda_end),
+            };
+            index = coda_end;
+            syllable_span
+        })
+        .collect()
+}
 
-    loop {
-        let curr_char = chars[index];
-        if !curr_char.is_vowel() {
-            if position == Position::None || position == Position::Onset {
-                if curr_char == 'y' {
-                    if syllable.onset.is_empty() {
-                        syllable.onset.push(curr_char);
-                        position = Position::Onset;
-                    } else {
-                        syllable.nucleus.push(curr_char);
-                        position = Position::Nucleus
-                    }
-                } else if curr_char == 'q' || curr_char == 'g' {
-                    syllable.onset.push(curr_char);
-                    position = Position::Onset;
-                    index += 1;
-                    if word_len <= index {
-                        return Err(InvalidWord)
-                    }
-                    let next_char = chars[index];
-                    if next_char == 'u' {
-                        index += 1;
-                        if word_len <= index {
-                            return Err(InvalidWord)
-                        }
-                        let after_next_char = chars[index];
-                        if after_next_char == 'i' || after_next_char == 'e' {
-                            syllable.onset.push(next_char);
-                            syllable.nucleus.push(after_next_char);
-                            position = Position::Nucleus;
-                        }
-                    } else {
-                        index -= 1;
-                    }
-                } else {
-                    syllable.onset.push(curr_char);
-                    position = Position::Onset;
-                }
-            } else if position == Position::Nucleus {
-                if curr_char == 'y'
-                    && (index == word_len - 1
-                        || (index + 1 < word_len && !chars[index + 1].is_vowel()))
-                {
-                    syllable.nucleus.push(curr_char);
-                } else if curr_char == 'h' {
-                    index += 1;
-                    if word_len <= index {
-                        return Err(InvalidWord)
-                    }
-                    if index == chars.len() {
-                        syllable.coda.push(curr_char);
-                        position = Position::Coda;
-                    } else {
-                        let next_char = chars[index];
-                        if next_char.is_vowel() {
-                            if syllable.nucleus.chars().count() == 1
-                                && can_form_hiatus(
-                                    syllable.nucleus.chars().next().unwrap(),
-                                    next_char,
-                                )
-                            {
-                                syllables.push(syllable);
-                                syllable = Syllable {
-                                    onset: curr_char.to_string(),
-                                    nucleus: next_char.to_string(),
-                                    coda: "".to_string(),
-                                };
-                                position = Position::Nucleus;
-                            } else {
-                                index += 1;
-                                if index == chars.len() {
-                                    syllable.nucleus.push(curr_char);
-                                    syllable.nucleus.push(next_char);
-                                    position = Position::Coda;
-                                } else {
-                                    let after_next_char = chars[index];
-                                    if after_next_char.is_vowel() {
-                                        if can_form_triphthong(
-                                            syllable.nucleus.chars().next().unwrap(),
-                                            next_char,
-                                            after_next_char,
-                                        ) {
-                                            // Could this happen?
-                                        } else {
-                                            syllables.push(syllable);
-                                            let mut nucleus = next_char.to_string();
-                                            nucleus.push(after_next_char);
-                                            syllable = Syllable {
-                                                onset: curr_char.to_string(),
-                                                nucleus,
-                                                coda: "".to_string(),
-                                            };
-                                            position = Position::Nucleus;
-                                        }
-                                    } else {
-                                        syllable.nucleus.push(curr_char);
-                                        syllable.nucleus.push(next_char);
-                                        syllable.coda.push(after_next_char);
-                                        position = Position::Coda;
-                                    }
-                                }
-                            }
-                        } else {
-                            syllable.coda.push(curr_char);
-                            syllables.push(syllable);
-                            syllable = Syllable {
-                                onset: next_char.to_string(),
-                                nucleus: "".to_string(),
-                                coda: "".to_string(),
-                            };
-                            position = Position::Onset;
-                        }
-                    }
-                } else {
-                    syllable.coda.push(curr_char);
-                    position = Position::Coda;
-                }
-            } else if position == Position::Coda {
-                if curr_char == 'y' {
-                    if syllable.coda.chars().count() == 1 {
-                        if index + 1 < word_len {
-                            if chars[index + 1].is_vowel() {
-                                syllables.push(syllable);
-                                syllable = Syllable {
-                                    onset: curr_char.to_string(),
-                                    nucleus: "".to_string(),
-                                    coda: "".to_string(),
-                                };
-                                position = Position::Onset;
-                            } else {
-                                let onset = syllable.coda.clone();
-                                syllable.coda.clear();
-                                syllables.push(syllable);
-                                syllable = Syllable {
-                                    onset,
-                                    nucleus: curr_char.to_string(),
-                                    coda: "".to_string(),
-                                };
-                                position = Position::Nucleus;
-                            }
-                        }
-                    } else if syllable.coda.chars().count() == 2 {
-                        if is_consonant_group(syllable.coda.as_str()) {
-                            let onset = syllable.coda.clone();
-                            syllable.coda = "".to_string();
-                            syllables.push(syllable);
-                            syllable = Syllable {
-                                onset,
-                                nucleus: curr_char.to_string(),
-                                coda: "".to_string(),
-                            };
-                            position = Position::Nucleus;
-                        } else {
-                            let chars: Vec<char> = syllable.coda.chars().collect();
-                            let onset = chars[1].to_string();
-                            syllable.coda = chars[0].to_string();
-                            syllables.push(syllable);
-                            syllable = Syllable {
-                                onset,
-                                nucleus: curr_char.to_string(),
-                                coda: "".to_string(),
-                            };
-                            position = Position::Nucleus;
-                        }
-                    } else {
-                        syllable.coda.push(curr_char);
-                    }
-                } else {
-                    syllable.coda.push(curr_char);
-                }
-            }
-        } else if position == Position::None || position == Position::Onset {
-            position = Position::Nucleus;
-            syllable.nucleus.push(curr_char);
-        } else if position == Position::Nucleus {
-            if syllable.nucleus.chars().count() == 1 {
-                if can_form_hiatus(syllable.nucleus.chars().next().unwrap(), curr_char) {
-                    syllables.push(syllable);
-                    syllable = Syllable {
-                        onset: "".to_string(),
-                        nucleus: curr_char.to_string(),
-                        coda: "".to_string(),
-                    };
-                } else {
-                    syllable.nucleus.push(curr_char);
-                }
-            } else if syllable.nucleus.chars().count() == 2 {
-                if can_form_triphthong(
-                    syllable.nucleus.chars().next().unwrap(),
-                    syllable.nucleus.chars().nth(1).unwrap(),
-                    curr_char,
-                ) {
-                    syllable.nucleus.push(curr_char);
-                } else {
-                    let last_nucleus = syllable.nucleus.chars().nth(1).unwrap();
-                    if last_nucleus.is_weak_vowel() {
-                        syllable.nucleus = syllable.nucleus.chars().next().unwrap().to_string();
-                        syllables.push(syllable);
-                        let mut last_nucleus = last_nucleus.to_string();
-                        last_nucleus.push(curr_char);
-                        syllable = Syllable {
-                            onset: "".to_string(),
-                            nucleus: last_nucleus,
-                            coda: "".to_string(),
-                        }
-                    } else {
-                        syllables.push(syllable);
-                        syllable = Syllable {
-                            onset: "".to_string(),
-                            nucleus: curr_char.to_string(),
-                            coda: "".to_string(),
-                        }
-                    }
-                }
-                position = Position::Nucleus;
-            }
-        } else if position == Position::Coda {
-            if syllable.coda.chars().count() == 1 {
-                let temp = syllable.coda.clone();
-                syllable.coda = "".to_string();
-                syllables.push(syllable);
-                syllable = Syllable {
-                    onset: temp,
-                    nucleus: curr_char.to_string(),
-                    coda: "".to_string(),
-                }
-            } else if syllable.coda.chars().count() == 2 {
-                let temp: String;
-                if is_consonant_group(syllable.coda.as_str()) {
-                    temp = syllable.coda.clone();
-                    syllable.coda = "".to_string();
-                } else {
-                    temp = syllable.coda.chars().nth(1).unwrap().to_string();
-                    syllable.coda = syllable.coda.chars().next().unwrap().to_string();
-                }
-                syllables.push(syllable);
-                syllable = Syllable {
-                    onset: temp,
-                    nucleus: curr_char.to_string(),
-                    coda: "".to_string(),
-                };
-            } else if syllable.coda.chars().count() == 3 {
-                let temp = syllable.coda.chars().skip(1).collect::<String>();
-                syllable.coda = syllable.coda.chars().next().unwrap().to_string();
-                syllables.push(syllable);
-                syllable = Syllable {
-                    onset: temp,
-                    nucleus: curr_char.to_string(),
-                    coda: "".to_string(),
-                }
-            } else if syllable.coda.chars().count() == 4 {
-                // indexing into &str should be fine because 4 char consonant
-                // clusters would only contain ascii letters, i.e., no `ñ`.
-                let temp = syllable.coda.as_str()[2..4].to_string();
-                syllable.coda = syllable.coda.as_str()[0..2].to_string();
-                syllables.push(syllable);
-                syllable = Syllable {
-                    onset: temp,
-                    nucleus: curr_char.to_string(),
-                    coda: "".to_string(),
-                }
-            }
-            position = Position::Nucleus;
-        }
+/// Builds owned syllables from the bounds found by [`syllable_bounds`].
+fn to_syllables(chars: &[char], bounds: &[Bounds]) -> Vec<Syllable> {
+    let mut start = 0;
+    bounds
+        .iter()
+        .map(|b| {
+            let part = |from: usize, len: usize| chars[from..from + len].iter().collect::<String>();
+            let syllable = Syllable {
+                onset: part(start, b.onset),
+                nucleus: part(start + b.onset, b.nucleus),
+                coda: part(start + b.onset + b.nucleus, b.coda),
+            };
+            start += b.len();
+            syllable
+        })
+        .collect()
+}
 
-        index += 1;
-        if index > word_len - 1 {
-            syllables.push(syllable);
-            break;
-        }
-    }
-    Ok(syllables)
+/// Splits a word into syllables, returning the number of characters in the
+/// parts of each syllable.
+pub(crate) fn syllable_bounds(chars: &[char]) -> Result<Vec<Bounds>> {
+    // Officially the longest word is 12 syllables, here we give some leeway
+    let mut bounds = Vec::with_capacity(32);
+    automaton::split(chars.iter().copied(), &mut bounds)?;
+    Ok(bounds)
+}
+
+pub(crate) fn identify_stress<S: Parts>(syllables: &[S]) -> usize {
+    stress_with_rule(syllables).map_or(0, |(index, _)| index)
 }
 
-fn identify_stress(syllables: &[Syllable]) -> usize {
+/// Finds the stressed syllable and the rule that picked it, or `None` if
+/// there are no syllables.
+pub(crate) fn stress_with_rule<S: Parts>(syllables: &[S]) -> Option<(usize, StressRule)> {
     let syllable_count = syllables.len();
-    if syllable_count == 0 || syllable_count == 1 {
-        return 0;
-    }
-    if syllable_count > 1 && syllables[syllable_count - 1].has_accented_vowel() {
-        return syllable_count - 1;
+    match syllable_count {
+        0 => return None,
+        1 => return Some((0, StressRule::Monosyllable)),
+        _ => {}
     }
-    if syllable_count >= 2 && syllables[syllable_count - 2].has_accented_vowel() {
-        return syllable_count - 2;
+    // A written accent marks the stress, wherever it is.
+    if let Some(index) = syllables.iter().rposition(|s| s.has_accented_vowel()) {
+        return Some((index, StressRule::WrittenAccent));
     }
-    if syllable_count >= 3 && syllables[syllable_count - 3].has_accented_vowel() {
-        return syllable_count - 3;
+
+    let last_syllable = &syllables[syllable_count - 1];
+    let last_char = |s: &str| composed(s).last().map(|(_, c)| c);
+    let ending = Ending {
+        nucleus_len: last_syllable.nucleus_len(),
+        coda_len: composed(last_syllable.coda()).count(),
+        last_char: last_char(last_syllable.coda()).or_else(|| last_char(last_syllable.nucleus())),
+    };
+    Some(unaccented_stress(syllable_count, &ending))
+}
+
+/// The last syllable of a word, as much as the stress rules need of it.
+pub(crate) struct Ending {
+    pub(crate) nucleus_len: usize,
+    pub(crate) coda_len: usize,
+    /// Last character of the coda, or of the nucleus if there's no coda.
+    pub(crate) last_char: Option<char>,
+}
+
+/// Stressed syllable of a word of two or more syllables without written
+/// accents, and the rule that picked it.
+pub(crate) fn unaccented_stress(syllable_count: usize, ending: &Ending) -> (usize, StressRule) {
+    let last_char = ending.last_char.unwrap_or_default();
+    if ending.coda_len == 0 {
+        if ending.nucleus_len == 3 {
+            return (syllable_count - 1, StressRule::Triphthong);
+        }
+        // Final 'y' counts as a consonant, "estoy", "virrey".
+        if ending.nucleus_len > 1 && matches!(last_char, 'y' | 'Y') {
+            return (syllable_count - 1, StressRule::FinalY);
+        }
+    } else if ending.coda_len > 1 || !matches!(last_char, 'n' | 's' | 'N' | 'S') {
+        return (syllable_count - 1, StressRule::EndsInConsonant);
     }
-    if syllable_count >= 4 {
-        let mut index = syllable_count as i8 - 4;
-        while index >= 0 {
-            if syllables[index as usize].has_accented_vowel() {
-                return index as usize;
+
+    (syllable_count - 2, StressRule::EndsInVowelNOrS)
+}
+
+/// Moves the first consonant of the groups in [`PENINSULAR_SPLITS`] to the
+/// coda of the previous syllable, "a-tle-ta" becomes "at-le-ta".
+fn split_regional_groups(chars: &[char], bounds: &mut [Bounds]) {
+    let mut start = bounds.first().map_or(0, |b| b.len());
+    for i in 1..bounds.len() {
+        let onset = &chars[start..start + bounds[i].onset];
+        if let [a, b, ..] = onset {
+            let pair = (a.to_ascii_lowercase(), b.to_ascii_lowercase());
+            if PENINSULAR_SPLITS.contains(&pair) {
+                bounds[i].onset -= 1;
+                bounds[i - 1].coda += 1;
             }
-            index -= 1;
         }
+        start += bounds[i].len();
     }
+}
 
-    let last_syllable = &syllables[syllable_count - 1];
-    if last_syllable.coda.is_empty() {
-        if last_syllable.nucleus.chars().count() == 3 {
-            return syllable_count - 1;
-        }
-    } else {
-        if last_syllable.coda != "n" && last_syllable.coda != "s" {
-            return syllable_count - 1;
-        }
+/// Stressed syllables of a word without hyphens, two for adverbs in -mente.
+fn part_stresses(word: &Word) -> Vec<usize> {
+    if word.syllables.is_empty() {
+        return vec![];
+    }
+    let written = word.to_string().to_lowercase();
+    let Some(adjective) = written.strip_suffix("mente") else {
+        return vec![word.stress_index];
+    };
+    if NOT_MENTE_ADVERBS.contains(&written.as_str()) {
+        return vec![word.stress_index];
+    }
+    let adjective = Word::from(adjective);
+    if adjective.syllables.len() + 2 != word.syllables.len() {
+        return vec![word.stress_index];
     }
+    vec![adjective.stress_index, adjective.syllables.len()]
+}
 
-    syllable_count - 2
+/// Names the stress of a word from its syllable count and stressed syllable.
+pub(crate) fn stress_type(syllable_count: usize, stress_index: usize) -> Option<StressType> {
+    let d = syllable_count.checked_sub(1)?.checked_sub(stress_index)?;
+    Some(match d {
+        0 => StressType::Oxytone,
+        1 => StressType::Paroxytone,
+        2 => StressType::Proparoxytone,
+        _ => StressType::Superproparoxytone,
+    })
 }
 
 #[cfg(test)]
@@ -823,6 +1229,43 @@ mod tests {
         assert_eq!(vowel_combos.triphthongs.len(), 0);
     }
 
+    #[test]
+    fn test_diptongo_y() {
+        for (s, kind) in [
+            ("muy", DiphthongType::Homogenous),
+            ("ley", DiphthongType::Falling),
+            ("estoy", DiphthongType::Falling),
+            ("rey", DiphthongType::Falling),
+        ] {
+            let vowel_combos = Word::from(s).vowel_combos();
+            assert_eq!(vowel_combos.diphthongs.len(), 1);
+            assert_eq!(vowel_combos.diphthongs[0].kind, kind);
+        }
+    }
+
+    #[test]
+    fn test_triptongo_y() {
+        let vowel_combos = Word::from("Paraguay").vowel_combos();
+        assert_eq!(vowel_combos.triphthongs.len(), 1);
+        assert_eq!(vowel_combos.triphthongs[0].composite, "uay");
+    }
+
+    #[test]
+    fn test_hiatus_after_diphthong() {
+        let s = "construía";
+        let vowel_combos = Word::from(s).vowel_combos();
+        assert_eq!(vowel_combos.diphthongs.len(), 1);
+        assert_eq!(vowel_combos.diphthongs[0].composite, "uí");
+        assert_eq!(vowel_combos.hiatuses.len(), 1);
+        let hiatus = &vowel_combos.hiatuses[0];
+        assert_eq!(hiatus.composite, "ía");
+        assert_eq!(hiatus.kind, HiatusType::Accentual);
+        assert_eq!(hiatus.syllable_index, 1);
+        assert_eq!(&s[hiatus.span.bytes.clone()], "ía");
+        assert_eq!(hiatus.span.chars, 7..9);
+        assert_eq!(Word::from("peine").vowel_combos().hiatuses.len(), 0);
+    }
+
     #[bench]
     fn bench_wordify(b: &mut Bencher) {
         b.iter(|| {
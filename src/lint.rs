@@ -0,0 +1,268 @@
+//! Spelling rules for Spanish graphemes: where 'ü', "qu", 'c' and 'z', 'm'
+//! and "rr" go.
+
+use crate::char_util::strip_accent;
+use crate::str_util::words;
+use crate::syllable::Span;
+
+/// Spelling rules checked by [`lint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintKind {
+    /// A 'u' sounded between 'g' and 'e' or 'i' takes a diaeresis,
+    /// "pingüino", and a diaeresis anywhere else is wrong, "agüa".
+    Diaeresis,
+    /// 'q' is only written in "que" and "qui", "cuando" not "quando", and
+    /// "queso" not "qeso".
+    Q,
+    /// 'z' before 'e' or 'i' is written 'c', "cero", except in a few
+    /// loanwords, "zigzag".
+    ZBeforeEOrI,
+    /// 'm', not 'n', goes before 'p' and 'b', "campo".
+    NBeforePOrB,
+    /// A strong 'r' between vowels is written "rr", "antirrobo", but a
+    /// single 'r' is enough after 'l', 'n' and 's', "honra".
+    R,
+    /// A hard 'k' sound is written "qu" before 'e' or 'i', "queso" not
+    /// "keso", and 'c' before other vowels, "casa" not "kasa", except in a
+    /// few loanwords, "kilo", "koala". Only a 'k' before a vowel is checked:
+    /// a 'c' before 'e' or 'i' is always soft, "cena", and "cue", "cui" are
+    /// right, "cuento", "cuidar".
+    HardC,
+}
+
+/// A spelling rule broken in the text, and how to fix it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    /// The letters breaking the rule.
+    pub span: Span,
+    pub kind: LintKind,
+    /// What the letters should be replaced with.
+    pub replacement: String,
+}
+
+/// Stems of words with a sounded 'u' between 'g' and 'e' or 'i', written
+/// here without the diaeresis.
+pub const DIAERESIS_STEMS: &[&str] = &[
+    "pinguin",
+    "linguis",
+    "lingue",
+    "cigue",
+    "vergue",
+    "antigue",
+    "aguero",
+    "desague",
+    "averigue",
+    "paraguer",
+    "nicaraguen",
+    "argui",
+    "unguent",
+    "exigue",
+    "ambigued",
+    "contigued",
+    "halagueñ",
+    "piraguis",
+    "lengueta",
+    "pedigue",
+];
+
+/// Stems of loanwords written with 'q' outside "que" and "qui".
+const Q_LOANWORDS: &[&str] = &["quorum", "quark", "quasar", "quater", "qatar", "iraq"];
+
+/// Stems of words written with 'z' before 'e' or 'i'.
+const Z_LOANWORDS: &[&str] = &[
+    "zeta", "zigzag", "zinc", "zen", "zepel", "nazi", "zejel", "zeugma", "zigot", "zipizape",
+    "kamikaze", "azimut", "enzima", "zingar", "zinnia", "zimbabue", "eczema", "zeus", "zeland",
+    "zelot",
+];
+
+/// Stems of words written with 'k' before a vowel.
+const K_LOANWORDS: &[&str] = &[
+    "kilo", "kiosk", "kerm", "ketchup", "keni", "kiwi", "kimono", "kinder", "karaoke", "bikini",
+    "poker", "kefir", "kepis", "kirie", "kitsch", "okey", "karat", "koala", "kayak", "kurd",
+    "kamikaz", "kaki", "karma", "kart", "kosher", "kebab", "kuwait", "tokio", "alask", "kosov",
+    "moka", "okupa", "eusk", "kafk", "yak",
+];
+
+/// Words with 'n' before 'p' or 'b'.
+const NB_LOANWORDS: &[&str] = &["canberra", "gutenberg", "inbox"];
+
+/// Prefixes that double the 'r' of the word they join, "antirrobo". "auto"
+/// isn't one of them, since "autoridad" is far more common than
+/// "autorretrato".
+const R_PREFIXES: &[&str] = &[
+    "anti", "contra", "infra", "extra", "ultra", "vice", "semi", "micro", "multi", "mini", "maxi",
+    "neo", "pseudo", "tele", "foto", "bio", "geo", "hidro", "electro", "termo", "pluri",
+];
+
+/// Stems that look like one of [`R_PREFIXES`] before a single 'r'.
+const R_EXCEPTIONS: &[&str] = &["contrari"];
+
+/// Checks the words of a text against Spanish spelling rules.
+///
+/// The rules are about how sounds are written, so words that are spelled
+/// right but mean something else aren't flagged. Loanwords that keep their
+/// spelling are listed in this module. A single 'r' for a strong one can
+/// only be spotted after a prefix.
+///
+/// # Example
+///
+/// ```
+/// use syllabize_es::lint::{fix, lint, LintKind};
+/// let text = "El pinguino come keso en el canpo.";
+/// let lints = lint(text);
+/// let kinds = lints.iter().map(|l| l.kind).collect::<Vec<_>>();
+/// assert_eq!(kinds, [LintKind::Diaeresis, LintKind::HardC, LintKind::NBeforePOrB]);
+/// assert_eq!(&text[lints[0].span.bytes.clone()], "u");
+/// assert_eq!(fix(text, &lints), "El pingüino come queso en el campo.");
+/// ```
+pub fn lint(text: &str) -> Vec<Lint> {
+    let mut lints = vec![];
+    for (offset, word) in words(text) {
+        let chars_before = text[..offset].chars().count();
+        for (start, len, kind, replacement) in lint_word(word) {
+            let bytes = word
+                .char_indices()
+                .map(|(i, _)| i)
+                .chain([word.len()])
+                .collect::<Vec<usize>>();
+            lints.push(Lint {
+                span: Span {
+                    bytes: offset + bytes[start]..offset + bytes[start + len],
+                    chars: chars_before + start..chars_before + start + len,
+                },
+                kind,
+                replacement,
+            });
+        }
+    }
+    lints
+}
+
+/// Applies the replacements of `lints`, found by [`lint`] in `text`.
+pub fn fix(text: &str, lints: &[Lint]) -> String {
+    let mut fixed = String::with_capacity(text.len());
+    let mut end = 0;
+    for lint in lints {
+        if lint.span.bytes.start < end {
+            continue;
+        }
+        fixed.push_str(&text[end..lint.span.bytes.start]);
+        fixed.push_str(&lint.replacement);
+        end = lint.span.bytes.end;
+    }
+    fixed.push_str(&text[end..]);
+    fixed
+}
+
+/// Lints a single word, returning the character index and length of each
+/// match, the rule, and the replacement.
+fn lint_word(word: &str) -> Vec<(usize, usize, LintKind, String)> {
+    let chars = word.chars().collect::<Vec<char>>();
+    let plain = chars
+        .iter()
+        .map(|c| strip_accent(*c).to_lowercase().next().unwrap_or(*c))
+        .collect::<Vec<char>>();
+    let text = plain.iter().collect::<String>();
+    let all_upper = !chars.iter().any(|c| c.is_lowercase());
+    let replace = |i: usize, len: usize, kind: LintKind, replacement: &str| {
+        let replacement = if all_upper && chars.len() > 1 {
+            replacement.to_uppercase()
+        } else if chars[i].is_uppercase() {
+            let mut r = replacement.chars();
+            r.next()
+                .map(|first| first.to_uppercase().chain(r).collect())
+                .unwrap_or_default()
+        } else {
+            replacement.to_string()
+        };
+        (i, len, kind, replacement)
+    };
+    let at = |i: usize| plain.get(i).copied();
+    let is_e_or_i = |c: Option<char>| matches!(c, Some('e' | 'i'));
+    let is_vowel = |c: Option<char>| matches!(c, Some('a' | 'e' | 'i' | 'o' | 'u' | 'ü'));
+    let has_stem = |stems: &[&str]| stems.iter().any(|s| text.contains(s));
+
+    let mut lints = vec![];
+    for stem in DIAERESIS_STEMS {
+        for (byte, _) in text.match_indices(stem) {
+            let gu = text[byte..]
+                .find("gu")
+                .map(|g| text[..byte + g].chars().count() + 1);
+            if let Some(u) = gu.filter(|&u| is_e_or_i(at(u + 1))) {
+                lints.push(replace(u, 1, LintKind::Diaeresis, "ü"));
+            }
+        }
+    }
+    for (i, &c) in plain.iter().enumerate() {
+        let next = at(i + 1);
+        match c {
+            'ü' if !(i > 0 && at(i - 1) == Some('g') && is_e_or_i(next)) => {
+                lints.push(replace(i, 1, LintKind::Diaeresis, "u"));
+            }
+            'q' if !(next == Some('u') && is_e_or_i(at(i + 2))) && !has_stem(Q_LOANWORDS) => {
+                let replacement = if is_e_or_i(next) { "qu" } else { "c" };
+                lints.push(replace(i, 1, LintKind::Q, replacement));
+            }
+            'z' if is_e_or_i(next) && !has_stem(Z_LOANWORDS) => {
+                lints.push(replace(i, 1, LintKind::ZBeforeEOrI, "c"));
+            }
+            'k' if is_vowel(next) && !has_stem(K_LOANWORDS) => {
+                let replacement = if is_e_or_i(next) { "qu" } else { "c" };
+                lints.push(replace(i, 1, LintKind::HardC, replacement));
+            }
+            'n' if matches!(next, Some('p' | 'b')) && !has_stem(NB_LOANWORDS) => {
+                lints.push(replace(i, 1, LintKind::NBeforePOrB, "m"));
+            }
+            'r' if next == Some('r') && i > 0 && matches!(at(i - 1), Some('l' | 'n' | 's')) => {
+                lints.push(replace(i, 2, LintKind::R, "r"));
+            }
+            _ => {}
+        }
+    }
+    let prefix = R_PREFIXES
+        .iter()
+        .filter(|p| text.starts_with(*p))
+        .map(|p| p.chars().count())
+        .max();
+    if let Some(len) = prefix
+        .filter(|&len| at(len) == Some('r') && is_vowel(at(len + 1)) && !has_stem(R_EXCEPTIONS))
+    {
+        lints.push(replace(len, 1, LintKind::R, "rr"));
+    }
+    lints.sort_by_key(|l| l.0);
+    lints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(word: &str) -> Vec<LintKind> {
+        lint_word(word).into_iter().map(|l| l.2).collect()
+    }
+
+    #[test]
+    fn test_lint_word() {
+        assert_eq!(kinds("pingüino"), []);
+        assert_eq!(kinds("guerra"), []);
+        assert_eq!(kinds("agüa"), [LintKind::Diaeresis]);
+        assert_eq!(kinds("quando"), [LintKind::Q]);
+        assert_eq!(kinds("quórum"), []);
+        assert_eq!(lint_word("qeso")[0].3, "qu");
+        assert_eq!(lint_word("Qién")[0].3, "Qu");
+        assert_eq!(kinds("cuento"), []);
+        assert_eq!(kinds("cuidar"), []);
+        assert_eq!(lint_word("kasa")[0].3, "c");
+        assert_eq!(kinds("inbox"), []);
+        assert_eq!(kinds("zigzag"), []);
+        assert_eq!(kinds("antirobo"), [LintKind::R]);
+        assert_eq!(kinds("contrario"), []);
+    }
+
+    #[test]
+    fn test_case() {
+        assert_eq!(lint_word("KESO")[0].3, "QU");
+        assert_eq!(lint_word("Keso")[0].3, "Qu");
+        assert_eq!(lint_word("PINGUINO")[0].3, "Ü");
+    }
+}
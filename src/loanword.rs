@@ -0,0 +1,149 @@
+//! Letters and letter groups of loanwords, for
+//! [`SyllabizeOptions::loanwords`](crate::SyllabizeOptions::loanwords).
+
+use crate::char_util::IsVowel;
+use crate::str_util::is_consonant_pair;
+use crate::syllable::Span;
+use crate::Bounds;
+
+/// Graphemes that don't belong to native Spanish spelling, in lowercase.
+pub const FOREIGN_GRAPHEMES: &[&str] = &["sh", "ck", "tz", "w", "k"];
+
+/// Pairs of letters that sound as a single consonant, "show", "hockey",
+/// "Quetzalcóatl".
+const UNITS: &[(char, char)] = &[('s', 'h'), ('c', 'k'), ('t', 'z')];
+
+fn lower(c: char) -> char {
+    c.to_ascii_lowercase()
+}
+
+fn is_w(c: char) -> bool {
+    lower(c) == 'w'
+}
+
+/// Returns the spans of the [`FOREIGN_GRAPHEMES`] in `word`, regardless of
+/// case.
+///
+/// # Example
+///
+/// ```
+/// use syllabize_es::loanword::foreign_graphemes;
+/// let spans = foreign_graphemes("Washington");
+/// let graphemes = spans.iter().map(|s| &"Washington"[s.bytes.clone()]).collect::<Vec<_>>();
+/// assert_eq!(graphemes, ["W", "sh"]);
+/// ```
+pub fn foreign_graphemes(word: &str) -> Vec<Span> {
+    let chars = word.char_indices().collect::<Vec<(usize, char)>>();
+    let mut spans = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let len = [2, 1]
+            .into_iter()
+            .find(|&len| {
+                let grapheme = chars
+                    .get(i..i + len)
+                    .map(|g| g.iter().map(|&(_, c)| lower(c)).collect::<String>());
+                grapheme.is_some_and(|g| FOREIGN_GRAPHEMES.contains(&g.as_str()))
+            })
+            .unwrap_or(0);
+        if len > 0 {
+            let end = chars.get(i + len).map_or(word.len(), |&(b, _)| b);
+            spans.push(Span {
+                bytes: chars[i].0..end,
+                chars: i..i + len,
+            });
+        }
+        i += len.max(1);
+    }
+    spans
+}
+
+/// Returns true if a syllable can start with the consonants `a` and `b`,
+/// "pla", "sha", "wha".
+fn can_open_syllable(a: char, b: char) -> bool {
+    is_consonant_pair(a, b) || UNITS.contains(&(lower(a), lower(b))) || is_w(b) || b.is_vowel()
+}
+
+/// Moves syllable boundaries so that the [`UNITS`] aren't split and 'w' is
+/// a semivowel after a vowel, "show", and a consonant of its own before one,
+/// "sánd-wich". Syllables only start with two consonants that can open one,
+/// "Wa-shing-ton".
+pub(crate) fn split_loanword(chars: &[char], bounds: &mut [Bounds]) {
+    let mut start = 0;
+    for i in 0..bounds.len() {
+        if i > 0 && bounds[i].onset > 0 {
+            let (a, b) = (chars[start - 1], chars[start]);
+            if bounds[i - 1].coda > 0 && UNITS.contains(&(lower(a), lower(b))) {
+                if bounds[i].onset > 1 {
+                    // "Ash-ley"
+                    bounds[i].onset -= 1;
+                    bounds[i - 1].coda += 1;
+                    start += 1;
+                } else {
+                    // "Que-tzal"
+                    bounds[i].onset += 1;
+                    bounds[i - 1].coda -= 1;
+                    start -= 1;
+                }
+            } else if bounds[i].onset == 2 && is_w(chars[start + 1]) && !b.is_vowel() {
+                // "sánd-wich"
+                bounds[i].onset -= 1;
+                bounds[i - 1].coda += 1;
+                start += 1;
+            }
+            while bounds[i].onset >= 2 && !can_open_syllable(chars[start], chars[start + 1]) {
+                // "Wa-shing-ton"
+                bounds[i].onset -= 1;
+                bounds[i - 1].coda += 1;
+                start += 1;
+            }
+        }
+        let nucleus_end = start + bounds[i].onset + bounds[i].nucleus;
+        if bounds[i].nucleus > 0 && bounds[i].coda > 0 && is_w(chars[nucleus_end]) {
+            bounds[i].nucleus += 1;
+            bounds[i].coda -= 1;
+        }
+        start += bounds[i].len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syllable_bounds;
+
+    fn split(word: &str) -> Vec<Bounds> {
+        let chars = word.chars().collect::<Vec<char>>();
+        let mut bounds = syllable_bounds(&chars).unwrap();
+        split_loanword(&chars, &mut bounds);
+        bounds
+    }
+
+    #[test]
+    fn test_split_loanword() {
+        assert_eq!(
+            split("show"),
+            [Bounds {
+                onset: 2,
+                nucleus: 2,
+                coda: 0
+            }]
+        );
+        assert_eq!(split("hockey")[1].onset, 2);
+        assert_eq!(split("Ashley")[0].coda, 2);
+        assert_eq!(split("Washington")[1].coda, 2);
+        assert_eq!(
+            split("casa"),
+            syllable_bounds(&['c', 'a', 's', 'a']).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_foreign_graphemes() {
+        assert_eq!(foreign_graphemes("casa"), []);
+        let spans = foreign_graphemes("ñakshw");
+        let chars = spans.iter().map(|s| s.chars.clone()).collect::<Vec<_>>();
+        assert_eq!(chars, [2..3, 3..5, 5..6]);
+        assert_eq!(spans[0].bytes, 3..4);
+    }
+}
@@ -0,0 +1,108 @@
+//! Plurals of nouns and adjectives, keeping the stress on the same vowel.
+
+use crate::accent::restress_at;
+use crate::char_util::{strip_accent, IsVowel};
+use crate::str_util::match_case;
+use crate::{StressType, Word};
+
+/// Plurals that don't follow from the general rules, mostly because the
+/// stress moves, "régimen", "regímenes".
+pub const PLURAL_EXCEPTIONS: &[(&str, &str)] = &[
+    ("carácter", "caracteres"),
+    ("régimen", "regímenes"),
+    ("espécimen", "especímenes"),
+    ("álbum", "álbumes"),
+    ("menú", "menús"),
+    ("champú", "champús"),
+    ("esquí", "esquís"),
+    ("jersey", "jerséis"),
+    ("espray", "espráis"),
+];
+
+/// Returns the plural of a noun or adjective, or `None` if the word can't be
+/// syllabized.
+///
+/// The tilde is added or removed as needed for the stress to stay on the
+/// same vowel, and the case of the ending follows that of the word.
+///
+/// # Example
+///
+/// ```
+/// use syllabize_es::plural::pluralize;
+/// assert_eq!(pluralize("joven").unwrap(), "jóvenes");
+/// assert_eq!(pluralize("canción").unwrap(), "canciones");
+/// assert_eq!(pluralize("lápiz").unwrap(), "lápices");
+/// assert_eq!(pluralize("crisis").unwrap(), "crisis");
+/// assert_eq!(pluralize("carácter").unwrap(), "caracteres");
+/// ```
+pub fn pluralize(word: &str) -> Option<String> {
+    if let Some(plural) = exception(word) {
+        return Some(plural);
+    }
+    let singular = Word::from(word);
+    let accent = singular.accent()?;
+    let mut stem = singular.to_string();
+    let last = stem.pop()?;
+    let before_last = stem.chars().last();
+    let suffix = match strip_accent(last).to_lowercase().next()? {
+        // Stressed 'í' and 'ú' take "es", "rubíes", "tabúes".
+        'i' | 'u' if last.is_accented_vowel() => "es",
+        // "pony", "ponis"
+        'y' if before_last.is_some_and(|c| !c.is_vowel()) => {
+            stem.push(if last.is_uppercase() { 'I' } else { 'i' });
+            stem.push(if last.is_uppercase() { 'S' } else { 's' });
+            return restress_at(&stem, accent.position);
+        }
+        'z' => {
+            stem.push(if last.is_uppercase() { 'C' } else { 'c' });
+            "es"
+        }
+        's' | 'x' => match singular.stress() {
+            // Only words stressed on the last syllable change, "autobuses",
+            // "lunes".
+            Some(StressType::Oxytone) => "es",
+            _ => return Some(singular.to_string()),
+        },
+        c if c.is_vowel() => "s",
+        'y' | 'l' | 'r' | 'n' | 'd' | 'j' => "es",
+        // "sándwiches", "maches"
+        'h' if before_last.is_some_and(|c| matches!(c, 'c' | 'C')) => "es",
+        _ => "s",
+    };
+    if last != 'z' && last != 'Z' {
+        stem.push(last);
+    }
+    if last.is_uppercase() {
+        stem.push_str(&suffix.to_uppercase());
+    } else {
+        stem.push_str(suffix);
+    }
+    restress_at(&stem, accent.position)
+}
+
+/// Looks up the word in [`PLURAL_EXCEPTIONS`], ignoring case but keeping it
+/// in the plural.
+fn exception(word: &str) -> Option<String> {
+    let lower = word.to_lowercase();
+    let (_, plural) = PLURAL_EXCEPTIONS.iter().find(|(s, _)| *s == lower)?;
+    Some(match_case(word, plural))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exception() {
+        assert_eq!(exception("Régimen"), Some("Regímenes".to_string()));
+        assert_eq!(exception("CARÁCTER"), Some("CARACTERES".to_string()));
+        assert_eq!(exception("casa"), None);
+    }
+
+    #[test]
+    fn test_case() {
+        assert_eq!(pluralize("CANCIÓN").unwrap(), "CANCIONES");
+        assert_eq!(pluralize("Luz").unwrap(), "Luces");
+        assert_eq!(pluralize(""), None);
+    }
+}
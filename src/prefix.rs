@@ -0,0 +1,137 @@
+//! Prefixes kept apart from the rest of the word, for
+//! [`SplitMode::Morphological`](crate::SplitMode::Morphological).
+
+use crate::char_util::strip_accent;
+use crate::Bounds;
+
+/// Prefixes whose last consonant stays with them, with the beginnings of
+/// the bases they're known to join, in lowercase and without tildes,
+/// "sub-ra-yar", "des-a-mor", "in-ú-til".
+///
+/// Only words made of a prefix and one of its bases are split, so words
+/// that merely start like a prefix keep their usual syllables, "e-ne-mi-go",
+/// "a-bue-lo", "pos-te-rior".
+pub const PREFIXED: &[(&str, &[&str])] = &[
+    (
+        "sub",
+        &[
+            "ray", "rog", "lun", "acuat", "altern", "arrend", "estim", "indic", "oficial", "urban",
+            "empleo", "ordin",
+        ],
+    ),
+    ("ab", &["rog"]),
+    ("ob", &["repci"]),
+    ("ad", &["renal"]),
+    (
+        "des",
+        &[
+            "amor", "arm", "ech", "esper", "ocup", "orden", "orient", "igual", "abrig", "acuerd",
+            "agrad", "alent", "anim", "aparec", "apeg", "aprob", "arroll", "ayun", "enchuf",
+            "emple", "envolv", "equilibr", "inform", "interes",
+        ],
+    ),
+    ("dis", &["ocia"]),
+    (
+        "in",
+        &[
+            "util", "acab", "acept", "adecu", "admis", "alter", "apel", "esper", "exact", "expert",
+            "igual", "oper", "usual", "edit",
+        ],
+    ),
+    ("en", &["ajen", "amor", "aren"]),
+    ("trans", &["atlant", "alpin", "andin", "oceanic", "acci"]),
+    (
+        "inter",
+        &[
+            "american", "accion", "activ", "urban", "ocean", "estatal", "insular",
+        ],
+    ),
+    ("super", &["abund", "ordenad", "yo"]),
+    ("post", &["operatori", "electoral"]),
+];
+
+/// Moves the syllable boundary to the end of a prefix, if the word is one
+/// of [`PREFIXED`] and the boundary fell inside the next onset.
+pub(crate) fn split_prefix(chars: &[char], bounds: &mut [Bounds]) {
+    let plain = chars
+        .iter()
+        .map(|c| strip_accent(*c).to_lowercase().next().unwrap_or(*c))
+        .collect::<String>();
+    let Some(len) = PREFIXED
+        .iter()
+        .filter(|(prefix, bases)| {
+            plain
+                .strip_prefix(prefix)
+                .is_some_and(|rest| bases.iter().any(|b| rest.starts_with(b)))
+        })
+        .map(|(prefix, _)| prefix.len())
+        .max()
+    else {
+        return;
+    };
+
+    let mut start = 0;
+    for i in 0..bounds.len() {
+        let end = start + bounds[i].len();
+        if end > len {
+            let moved = len - start;
+            if i > 0 && moved > 0 && moved <= bounds[i].onset {
+                bounds[i].onset -= moved;
+                bounds[i - 1].coda += moved;
+            }
+            return;
+        }
+        start = end;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{syllable_bounds, SplitMode, SyllabizeOptions, Word};
+
+    fn split(word: &str) -> String {
+        let options = SyllabizeOptions {
+            mode: SplitMode::Morphological,
+            ..Default::default()
+        };
+        Word::parse_with_options(word, options)
+            .unwrap()
+            .syllabize("-")
+    }
+
+    #[test]
+    fn test_split_prefix() {
+        assert_eq!(split("subrayar"), "sub-ra-yar");
+        assert_eq!(split("desamor"), "des-a-mor");
+        assert_eq!(split("inútil"), "in-ú-til");
+        assert_eq!(split("transatlántico"), "trans-a-tlán-ti-co");
+        assert_eq!(split("interamericano"), "in-ter-a-me-ri-ca-no");
+        assert_eq!(split("postoperatorio"), "post-o-pe-ra-to-rio");
+        assert_eq!(split("abrogar"), "ab-ro-gar");
+    }
+
+    #[test]
+    fn test_not_prefixed() {
+        for (word, syllables) in [
+            ("desierto", "de-sier-to"),
+            ("desear", "de-se-ar"),
+            ("enero", "e-ne-ro"),
+            ("sublime", "su-bli-me"),
+            ("superhombre", "su-per-hom-bre"),
+            ("casa", "ca-sa"),
+            ("in", "in"),
+        ] {
+            assert_eq!(split(word), syllables);
+        }
+    }
+
+    #[test]
+    fn test_bounds_unchanged_without_prefix() {
+        let chars = "palabra".chars().collect::<Vec<char>>();
+        let mut bounds = syllable_bounds(&chars).unwrap();
+        let before = bounds.clone();
+        split_prefix(&chars, &mut bounds);
+        assert_eq!(bounds, before);
+    }
+}
@@ -0,0 +1,183 @@
+//! The 2010 RAE orthography, and spellings it made obsolete.
+
+use crate::char_util::{strip_accent, IsVowel};
+use crate::str_util::words;
+
+/// Which RAE orthography the tilde rules follow.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Orthography {
+    /// Ortografía de 1999. Words in [`HIATUS_MONOSYLLABLES`] take a tilde
+    /// if pronounced with a hiatus, so "guion" and "guión" are both right.
+    Rae1999,
+    /// Ortografía de 2010. Any weak vowel next to another vowel makes a
+    /// diphthong for the tilde rules, so "guion" is a monosyllable.
+    #[default]
+    Rae2010,
+}
+
+/// Words that may be pronounced with a hiatus but are monosyllables for
+/// the tilde rules since 2010, "guion", "truhan", "fie", "liais", "hui".
+pub const HIATUS_MONOSYLLABLES: &[&str] = &[
+    "crie", "crio", "criais", "crieis", "fie", "fio", "fiais", "fieis", "flie", "flio", "fliais",
+    "flieis", "frio", "friais", "guie", "guio", "guiais", "guieis", "hui", "huis", "lie", "lio",
+    "liais", "lieis", "pio", "piais", "pieis", "rio", "riais", "guion", "ion", "muon", "pion",
+    "prion", "ruan", "truhan", "sion",
+];
+
+/// Demonstrative pronouns, with the tilde they took before 2010 to tell
+/// them apart from determiners. "está" and "esté" are forms of "estar".
+const DEMONSTRATIVES: &[&str] = &[
+    "éste",
+    "ésta",
+    "éstos",
+    "éstas",
+    "ése",
+    "ésa",
+    "ésos",
+    "ésas",
+    "aquél",
+    "aquélla",
+    "aquéllos",
+    "aquéllas",
+];
+
+/// Returns true if the word, ignoring case and tildes, is one of
+/// [`HIATUS_MONOSYLLABLES`], and any tilde it has could only mark a hiatus
+/// monosyllable.
+///
+/// A tilde on a weak vowel next to a strong one breaks the diphthong under
+/// any orthography, so "río" and "frío" have two syllables and aren't
+/// monosyllables, while "rió" and "guión" are.
+pub fn is_hiatus_monosyllable(word: &str) -> bool {
+    HIATUS_MONOSYLLABLES.contains(&plain(word).as_str()) && !has_hiatus_tilde(word)
+}
+
+/// Returns true if a weak vowel next to a strong one has a tilde, "río".
+fn has_hiatus_tilde(word: &str) -> bool {
+    let chars = word
+        .chars()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .collect::<Vec<char>>();
+    let is_strong = |i: usize| {
+        chars
+            .get(i)
+            .is_some_and(|c| c.is_vowel() && !c.is_weak_vowel())
+    };
+    chars.iter().enumerate().any(|(i, &c)| {
+        c.is_accented_vowel()
+            && strip_accent(c).is_weak_vowel()
+            && ((i > 0 && is_strong(i - 1)) || is_strong(i + 1))
+    })
+}
+
+/// Kinds of spellings that the 2010 orthography dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReformKind {
+    /// A tilde on a word of [`HIATUS_MONOSYLLABLES`], "guión".
+    HiatusMonosyllable,
+    /// "sólo" for the adverb.
+    Solo,
+    /// A tilde on a demonstrative pronoun, "éste".
+    Demonstrative,
+    /// A tilde on the conjunction "o" between numbers, "3 ó 4".
+    DisjunctiveO,
+}
+
+/// A spelling that the 2010 orthography no longer uses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReformHint {
+    /// Byte offset of the word in the text.
+    pub offset: usize,
+    /// The word as written.
+    pub word: String,
+    /// The word as the 2010 orthography writes it.
+    pub suggestion: String,
+    pub kind: ReformKind,
+}
+
+/// Finds spellings from before the 2010 orthography in running text.
+///
+/// The tildes of "sólo" and the demonstratives are still allowed to avoid
+/// an ambiguity, but the RAE advises against them, so they're flagged too.
+///
+/// # Example
+///
+/// ```
+/// use syllabize_es::reform::{check_reform, ReformKind};
+/// let hints = check_reform("Sólo leí el guión, 3 ó 4 veces.");
+/// let found = hints.iter().map(|h| (h.suggestion.as_str(), h.kind)).collect::<Vec<_>>();
+/// assert_eq!(
+///     found,
+///     [
+///         ("Solo", ReformKind::Solo),
+///         ("guion", ReformKind::HiatusMonosyllable),
+///         ("o", ReformKind::DisjunctiveO),
+///     ]
+/// );
+/// ```
+pub fn check_reform(text: &str) -> Vec<ReformHint> {
+    let mut hints = vec![];
+    for (offset, word) in words(text) {
+        if !word.chars().any(|c| strip_accent(c) != c) {
+            continue;
+        }
+        let plain = plain(word);
+        let lower = word.to_lowercase();
+        let kind = if is_hiatus_monosyllable(word) {
+            ReformKind::HiatusMonosyllable
+        } else if plain == "solo" {
+            ReformKind::Solo
+        } else if DEMONSTRATIVES.contains(&lower.as_str()) {
+            ReformKind::Demonstrative
+        } else if plain == "o" && between_digits(text, offset, word.len()) {
+            ReformKind::DisjunctiveO
+        } else {
+            continue;
+        };
+        hints.push(ReformHint {
+            offset,
+            word: word.to_string(),
+            suggestion: word.chars().map(strip_accent).collect(),
+            kind,
+        });
+    }
+    hints
+}
+
+/// The word in lowercase, without tildes.
+fn plain(word: &str) -> String {
+    word.chars()
+        .map(strip_accent)
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Returns true if the word at `offset` has digits on both sides, ignoring
+/// spaces.
+fn between_digits(text: &str, offset: usize, len: usize) -> bool {
+    let is_digit = |c: Option<char>| c.is_some_and(|c| c.is_ascii_digit());
+    is_digit(text[..offset].trim_end().chars().last())
+        && is_digit(text[offset + len..].trim_start().chars().next())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_between_digits() {
+        assert!(between_digits("3 ó 4", 2, 2));
+        assert!(between_digits("3ó4", 1, 2));
+        assert!(!between_digits("a ó 4", 2, 2));
+    }
+
+    #[test]
+    fn test_is_hiatus_monosyllable() {
+        for word in ["guion", "guión", "rió", "Truhán", "huí", "liáis"] {
+            assert!(is_hiatus_monosyllable(word), "{}", word);
+        }
+        for word in ["río", "frío", "lío", "crío", "guío", "RÍO", "mar"] {
+            assert!(!is_hiatus_monosyllable(word), "{}", word);
+        }
+    }
+}
@@ -0,0 +1,266 @@
+//! Consonant groups and fixed splits that users can extend, for
+//! [`Word::parse_with`](crate::Word::parse_with).
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::char_util::IsVowel;
+use crate::lexicon::Lexicon;
+use crate::str_util::{is_consonant_pair, CONSONANT_GROUPS};
+use crate::{composed_chars, syllable_bounds, Bounds, SyllabizeOptions};
+
+/// Rules for splitting words, on top of the built-in ones.
+///
+/// Rules can be written in a text file, one per line:
+///
+/// ```text
+/// # Nahuatl
+/// cluster tz
+/// # Peninsular "tl"
+/// separate tl
+/// word Mé-xi-co
+/// ```
+///
+/// `cluster` adds a consonant pair that opens a syllable together,
+/// `separate` removes one of [`CONSONANT_GROUPS`], and `word` fixes the
+/// syllables of a word, regardless of case. Everything after a '#' is
+/// ignored. Words with an unusual stress as well go in the
+/// [`Lexicon`].
+///
+/// # Example
+///
+/// ```
+/// use syllabize_es::rules::SyllabizeRules;
+/// use syllabize_es::Word;
+/// let rules = "cluster tz\nword Wind-sor".parse::<SyllabizeRules>().unwrap();
+/// let word = Word::parse_with("Quetzalcóatl", &rules).unwrap();
+/// assert_eq!(word.syllabize("-"), "Que-tzal-có-atl");
+/// let word = Word::parse_with("windsor", &rules).unwrap();
+/// assert_eq!(word.syllabize("-"), "wind-sor");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyllabizeRules {
+    /// Consonant pairs, in lowercase, that open a syllable together.
+    pub clusters: Vec<(char, char)>,
+    /// Words with fixed syllables, separated by hyphens.
+    pub words: Vec<String>,
+    /// Words with fixed syllables and stress, consulted before the rest.
+    pub lexicon: Lexicon,
+    /// How words that aren't fixed are split, "sub-ra-yar", "at-le-ta".
+    pub options: SyllabizeOptions,
+}
+
+impl Default for SyllabizeRules {
+    fn default() -> Self {
+        SyllabizeRules {
+            clusters: CONSONANT_GROUPS.to_vec(),
+            words: vec![],
+            lexicon: Lexicon::default(),
+            options: SyllabizeOptions::default(),
+        }
+    }
+}
+
+/// Reasons why rules can't be read from text.
+///
+/// Lines are counted from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RulesError {
+    /// The line doesn't start with `cluster`, `separate` or `word`.
+    UnknownDirective { line: usize },
+    /// The cluster isn't two consonants.
+    InvalidCluster { line: usize },
+    /// A syllable of the word has no vowel.
+    InvalidWord { line: usize },
+}
+
+impl fmt::Display for RulesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RulesError::UnknownDirective { line } => {
+                write!(f, "unknown directive on line {}", line)
+            }
+            RulesError::InvalidCluster { line } => write!(f, "invalid cluster on line {}", line),
+            RulesError::InvalidWord { line } => write!(f, "invalid word split on line {}", line),
+        }
+    }
+}
+
+impl std::error::Error for RulesError {}
+
+impl FromStr for SyllabizeRules {
+    type Err = RulesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rules = SyllabizeRules::default();
+        for (i, line) in s.lines().enumerate() {
+            let line_no = i + 1;
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let Some((directive, value)) = line.split_once(char::is_whitespace) else {
+                if line.is_empty() {
+                    continue;
+                }
+                return Err(RulesError::UnknownDirective { line: line_no });
+            };
+            let value = value.trim();
+            match directive {
+                "cluster" | "separate" => {
+                    let pair =
+                        cluster(value).ok_or(RulesError::InvalidCluster { line: line_no })?;
+                    rules.clusters.retain(|&c| c != pair);
+                    if directive == "cluster" {
+                        rules.clusters.push(pair);
+                    }
+                }
+                "word" => {
+                    if fixed_bounds(value).is_none() {
+                        return Err(RulesError::InvalidWord { line: line_no });
+                    }
+                    rules.words.push(value.to_string());
+                }
+                _ => return Err(RulesError::UnknownDirective { line: line_no }),
+            }
+        }
+        Ok(rules)
+    }
+}
+
+impl SyllabizeRules {
+    /// Bounds of the syllables of `chars`, and the stressed one, if they're
+    /// in the lexicon or one of the fixed words.
+    pub(crate) fn fixed_split(&self, chars: &[char]) -> Option<(Vec<Bounds>, Option<usize>)> {
+        if let Some((exception, bounds)) = self.lexicon.find(chars) {
+            return Some((bounds.to_vec(), Some(exception.stress_index)));
+        }
+        self.words
+            .iter()
+            .filter(|w| same_spelling(&w.replace('-', ""), chars))
+            .find_map(|w| fixed_bounds(w))
+            .map(|bounds| (bounds, None))
+    }
+
+    /// Moves syllable boundaries so that consonant pairs between vowels are
+    /// split or kept together as [`SyllabizeRules::clusters`] say.
+    pub(crate) fn regroup(&self, chars: &[char], bounds: &mut [Bounds]) {
+        let lower = |c: char| c.to_lowercase().next().unwrap_or(c);
+        let mut start = bounds.first().map_or(0, |b| b.len());
+        for i in 1..bounds.len() {
+            let onset = &chars[start..start + bounds[i].onset];
+            let coda_end = start;
+            start += bounds[i].len();
+            match *onset {
+                [a, b, ..]
+                    if is_consonant_pair(a, b)
+                        && !self.clusters.contains(&(lower(a), lower(b))) =>
+                {
+                    bounds[i].onset -= 1;
+                    bounds[i - 1].coda += 1;
+                }
+                [b] if bounds[i - 1].coda > 0 => {
+                    let a = chars[coda_end - 1];
+                    if !a.is_vowel()
+                        && !b.is_vowel()
+                        && !is_consonant_pair(a, b)
+                        && self.clusters.contains(&(lower(a), lower(b)))
+                    {
+                        bounds[i].onset += 1;
+                        bounds[i - 1].coda -= 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Returns true if `word` is spelled with `chars`, regardless of case.
+pub(crate) fn same_spelling(word: &str, chars: &[char]) -> bool {
+    let (spelling, _) = composed_chars(word);
+    spelling.len() == chars.len()
+        && spelling
+            .iter()
+            .zip(chars)
+            .all(|(a, b)| a.to_lowercase().eq(b.to_lowercase()))
+}
+
+/// The pair of consonants in `value`, in lowercase.
+fn cluster(value: &str) -> Option<(char, char)> {
+    let mut chars = value.chars().flat_map(char::to_lowercase);
+    match (chars.next(), chars.next(), chars.next()) {
+        (Some(a), Some(b), None) if is_consonant(a) && is_consonant(b) => Some((a, b)),
+        _ => None,
+    }
+}
+
+fn is_consonant(c: char) -> bool {
+    c.is_alphabetic() && !c.is_vowel()
+}
+
+/// Bounds of a word split with hyphens, one syllable per part.
+pub(crate) fn fixed_bounds(word: &str) -> Option<Vec<Bounds>> {
+    word.split('-')
+        .map(|part| {
+            let (chars, _) = composed_chars(part);
+            let bounds = syllable_bounds(&chars).ok()?;
+            let (first, last) = (bounds.first()?, bounds.last()?);
+            let (onset, coda) = (first.onset, last.coda);
+            let nucleus = chars.len().checked_sub(onset + coda)?;
+            let vowels = &chars[onset..onset + nucleus];
+            vowels.iter().any(|c| c.can_be_nucleus()).then_some(Bounds {
+                onset,
+                nucleus,
+                coda,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_bounds() {
+        assert_eq!(
+            fixed_bounds("Wind-sor").unwrap()[0],
+            Bounds {
+                onset: 1,
+                nucleus: 1,
+                coda: 2
+            }
+        );
+        assert_eq!(fixed_bounds("cre-er").unwrap().len(), 2);
+        assert_eq!(fixed_bounds("Wind-s-or"), None);
+        assert_eq!(fixed_bounds("tl-a"), None);
+    }
+
+    #[test]
+    fn test_from_str() {
+        let rules = "# comment\n\ncluster TZ\nseparate tl  # spain\nword a-tle-ta"
+            .parse::<SyllabizeRules>()
+            .unwrap();
+        assert!(rules.clusters.contains(&('t', 'z')));
+        assert!(!rules.clusters.contains(&('t', 'l')));
+        assert_eq!(rules.words, ["a-tle-ta"]);
+        assert_eq!(
+            "cluster t".parse::<SyllabizeRules>(),
+            Err(RulesError::InvalidCluster { line: 1 })
+        );
+        assert_eq!(
+            "cluster ae".parse::<SyllabizeRules>(),
+            Err(RulesError::InvalidCluster { line: 1 })
+        );
+        assert_eq!(
+            "\nsplit tl".parse::<SyllabizeRules>(),
+            Err(RulesError::UnknownDirective { line: 2 })
+        );
+        assert_eq!(
+            "word".parse::<SyllabizeRules>(),
+            Err(RulesError::UnknownDirective { line: 1 })
+        );
+        assert_eq!(
+            "word tl-a".parse::<SyllabizeRules>(),
+            Err(RulesError::InvalidWord { line: 1 })
+        );
+    }
+}
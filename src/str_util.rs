@@ -1,42 +1,65 @@
-use crate::char_util::IsVowel;
+use std::borrow::Cow;
+use std::iter::Peekable;
+use std::str::CharIndices;
 
-/// Returns true if the given string is a consonant blend or a digraph
+use crate::char_util::{compose, is_combining_mark, IsVowel};
+
+/// Returns true if the given string is a consonant blend or a digraph,
+/// regardless of case.
 pub fn is_consonant_group(s: &str) -> bool {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next(), chars.next()) {
+        (Some(a), Some(b), None) => is_consonant_pair(a, b),
+        _ => false,
+    }
+}
+
+/// Returns true if the two characters are a consonant blend or a digraph,
+/// regardless of case.
+pub fn is_consonant_pair(a: char, b: char) -> bool {
     matches!(
-        s,
-        "bl" | "fl"
-            | "cl"
-            | "gl"
-            | "pl"
-            | "cr"
-            | "br"
-            | "tr"
-            | "gr"
-            | "fr"
-            | "pr"
-            | "dr"
-            | "tl"
-            | "ch"
-            | "ll"
-            | "rr"
+        (a.to_ascii_lowercase(), b.to_ascii_lowercase()),
+        ('b' | 'f' | 'c' | 'g' | 'p', 'l')
+            | ('c' | 'b' | 't' | 'g' | 'f' | 'p' | 'd', 'r')
+            | ('t', 'l')
+            | ('c', 'h')
+            | ('l', 'l')
+            | ('r', 'r')
     )
 }
 
-/// Returns index of the stressed vowel in the given string of vowels
+/// The consonant blends and digraphs of [`is_consonant_pair`], in lowercase.
+pub const CONSONANT_GROUPS: &[(char, char)] = &[
+    ('b', 'l'),
+    ('f', 'l'),
+    ('c', 'l'),
+    ('g', 'l'),
+    ('p', 'l'),
+    ('c', 'r'),
+    ('b', 'r'),
+    ('t', 'r'),
+    ('g', 'r'),
+    ('f', 'r'),
+    ('p', 'r'),
+    ('d', 'r'),
+    ('t', 'l'),
+    ('c', 'h'),
+    ('l', 'l'),
+    ('r', 'r'),
+];
+
+/// Returns index of the stressed vowel in the given string of vowels, or 0 if
+/// the string is empty.
 pub fn stress_index(s: &str) -> usize {
     let mut index = 0;
     for ch in s.chars() {
         if ch.is_stressed_vowel() {
-            break;
+            return index;
         }
         index += 1;
     }
-    if index == s.len() {
-        // No stressed vowel, take the last, eg. "i" of "ui".
-        index - 1
-    } else {
-        index
-    }
+    // No stressed vowel, take the last, eg. "i" of "ui".
+    index.saturating_sub(1)
 }
 
 pub fn loose_match(s: &str, t: &str) -> bool {
@@ -62,6 +85,97 @@ pub fn is_both_b_or_v(a: &str, b: &str) -> bool {
     a == "b" && b == "v" || a == "v" && b == "b"
 }
 
+/// Iterator over the characters of a string, with combining acute accents,
+/// diaereses and tildes folded into the preceding letter.
+///
+/// Yields the byte offset of each character in the original string, like
+/// [`str::char_indices`]. Combining marks that can't be folded are yielded
+/// unchanged.
+pub struct Composed<'a> {
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl Iterator for Composed<'_> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (offset, c) = self.chars.next()?;
+        if let Some(composed) = self.chars.peek().and_then(|&(_, mark)| compose(c, mark)) {
+            self.chars.next();
+            return Some((offset, composed));
+        }
+        Some((offset, c))
+    }
+}
+
+/// Returns an iterator that composes decomposed (NFD) accents on the fly.
+///
+/// ```
+/// use syllabize_es::str_util::composed;
+/// let s = composed("cancio\u{301}n").map(|(_, c)| c).collect::<String>();
+/// assert_eq!(s, "canción");
+/// ```
+pub fn composed(s: &str) -> Composed<'_> {
+    Composed {
+        chars: s.char_indices().peekable(),
+    }
+}
+
+/// Composes decomposed accents, borrowing the input if nothing changes.
+pub fn normalize(s: &str) -> Cow<'_, str> {
+    if !s.chars().any(is_combining_mark) {
+        return Cow::Borrowed(s);
+    }
+    let normalized = composed(s).map(|(_, c)| c).collect::<String>();
+    if normalized == s {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(normalized)
+    }
+}
+
+/// Returns `word`, which is in lowercase, in the case of `model`: all
+/// uppercase, capitalized, or left as it is.
+pub fn match_case(model: &str, word: &str) -> String {
+    let mut chars = model.chars();
+    match chars.next() {
+        Some(first) if first.is_uppercase() && chars.clone().any(char::is_alphabetic) => {
+            if chars.all(|c| !c.is_lowercase()) {
+                word.to_uppercase()
+            } else {
+                capitalize(word)
+            }
+        }
+        Some(first) if first.is_uppercase() => capitalize(word),
+        _ => word.to_string(),
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// Words of running text, with their byte offsets.
+///
+/// Combining marks belong to the word, so decomposed accents don't split it.
+pub(crate) fn words(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut start = None;
+    text.char_indices()
+        .chain([(text.len(), ' ')])
+        .filter_map(move |(i, c)| {
+            if c.is_alphabetic() || (start.is_some() && is_combining_mark(c)) {
+                start.get_or_insert(i);
+                None
+            } else {
+                start.take().map(|s| (s, &text[s..i]))
+            }
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,13 +183,57 @@ mod tests {
     #[test]
     fn test_is_consonant_group() {
         assert!(is_consonant_group("cl"));
+        assert!(is_consonant_group("RR"));
+        assert!(is_consonant_group("Ch"));
         assert!(!is_consonant_group("cy"));
     }
 
+    #[test]
+    fn test_consonant_groups() {
+        let letters = ('a'..='z').collect::<Vec<char>>();
+        for &a in &letters {
+            for &b in &letters {
+                assert_eq!(
+                    is_consonant_pair(a, b),
+                    CONSONANT_GROUPS.contains(&(a, b)),
+                    "{}{}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_stress_index() {
         assert_eq!(stress_index("ui"), 1);
         assert_eq!(stress_index("ai"), 0);
+        assert_eq!(stress_index("üi"), 1);
+        assert_eq!(stress_index(""), 0);
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert!(matches!(normalize("canción"), Cow::Borrowed("canción")));
+        assert_eq!(normalize("pingu\u{308}ino"), "pingüino");
+        assert_eq!(normalize("an\u{303}o"), "año");
+        assert!(matches!(normalize("a\u{300}"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_match_case() {
+        assert_eq!(match_case("CARÁCTER", "caracteres"), "CARACTERES");
+        assert_eq!(match_case("Que", "qué"), "Qué");
+        assert_eq!(match_case("É", "él"), "Él");
+        assert_eq!(match_case("que", "qué"), "qué");
+    }
+
+    #[test]
+    fn test_words() {
+        let found = words("¿Éste, o aquél?").collect::<Vec<_>>();
+        assert_eq!(found, [(2, "Éste"), (9, "o"), (11, "aquél")]);
+        let found = words("la cancio\u{301}n").collect::<Vec<_>>();
+        assert_eq!(found, [(0, "la"), (3, "cancio\u{301}n")]);
     }
 
     #[test]
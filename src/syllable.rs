@@ -1,4 +1,5 @@
 use std::fmt::{self, Display};
+use std::ops::Range;
 
 use crate::char_util::*;
 use crate::str_util::stress_index;
@@ -29,7 +30,7 @@ impl Syllable {
     /// assert_eq!(syllable.has_accented_vowel(), false);
     /// ```
     pub fn has_accented_vowel(&self) -> bool {
-        for c in self.nucleus.chars().into_iter() {
+        for c in self.nucleus.chars() {
             if c.is_accented_vowel() {
                 return true;
             }
@@ -60,9 +61,66 @@ impl Syllable {
     }
 }
 
+/// A range of the original input, in bytes and in characters.
+///
+/// Byte ranges can be used to slice the input, character ranges to map into
+/// editor buffers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Span {
+    pub bytes: Range<usize>,
+    pub chars: Range<usize>,
+}
+
+impl Span {
+    /// Returns the span from the start of this one to the end of `other`.
+    pub fn to(&self, other: &Span) -> Span {
+        Span {
+            bytes: self.bytes.start..other.bytes.end,
+            chars: self.chars.start..other.chars.end,
+        }
+    }
+}
+
+/// Location of a syllable and each of its parts in the original input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyllableSpan {
+    pub syllable: Span,
+    pub onset: Span,
+    pub nucleus: Span,
+    pub coda: Span,
+}
+
+/// Read access to the parts of owned and borrowed syllables, for the rules
+/// that work on both.
+pub(crate) trait Parts {
+    fn nucleus(&self) -> &str;
+    fn coda(&self) -> &str;
+    fn has_accented_vowel(&self) -> bool;
+    /// Number of vowels in the nucleus, after composing decomposed accents.
+    fn nucleus_len(&self) -> usize;
+}
+
+impl Parts for Syllable {
+    fn nucleus(&self) -> &str {
+        &self.nucleus
+    }
+
+    fn coda(&self) -> &str {
+        &self.coda
+    }
+
+    fn has_accented_vowel(&self) -> bool {
+        Syllable::has_accented_vowel(self)
+    }
+
+    fn nucleus_len(&self) -> usize {
+        self.nucleus.chars().count()
+    }
+}
+
 impl Display for Syllable {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}{}{}", &self.onset, &self.nucleus, &self.coda)
+        write!(f, "{}{}{}", self.onset, self.nucleus, self.coda)
     }
 }
 
@@ -80,6 +138,25 @@ mod tests {
         assert_eq!(format!("{}", s), "bat");
     }
 
+    #[test]
+    fn span_to() {
+        let a = Span {
+            bytes: 0..1,
+            chars: 0..1,
+        };
+        let b = Span {
+            bytes: 2..4,
+            chars: 2..3,
+        };
+        assert_eq!(
+            a.to(&b),
+            Span {
+                bytes: 0..4,
+                chars: 0..3
+            }
+        );
+    }
+
     #[test]
     fn vowels_since_stress() {
         let s = Syllable {
@@ -0,0 +1,272 @@
+//! Borrowed counterparts of [`Word`] and [`Syllable`], slicing into the input
+//! instead of allocating strings for every syllable.
+
+use std::fmt::{self, Display};
+use std::iter;
+use std::ops::Range;
+
+use crate::char_util::IsVowel;
+use crate::str_util::composed;
+use crate::syllable::{Parts, Syllable};
+use crate::{
+    automaton, checked_bounds, composed_chars, identify_stress, stress_type, Bounds, ParseError,
+    StressType, Word,
+};
+
+/// A syllable whose parts are slices of the parsed input.
+///
+/// Decomposed accents are kept as they appear in the input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyllableRef<'a> {
+    pub onset: &'a str,
+    pub nucleus: &'a str,
+    pub coda: &'a str,
+    /// Byte range of the whole syllable in the input.
+    pub bytes: Range<usize>,
+}
+
+impl<'a> SyllableRef<'a> {
+    /// Returns true if there is an accented vowel in the nucleus.
+    pub fn has_accented_vowel(&self) -> bool {
+        composed(self.nucleus).any(|(_, c)| c.is_accented_vowel())
+    }
+
+    /// Returns part of the nucleus starting from the stressed vowel.
+    ///
+    /// ```
+    /// use syllabize_es::word_ref::WordRef;
+    /// let word = WordRef::from("buey");
+    /// assert_eq!(word.syllables[0].vowels_since_stress(), "ey");
+    /// ```
+    pub fn vowels_since_stress(&self) -> &'a str {
+        let mut last = None;
+        for (offset, c) in composed(self.nucleus) {
+            if c.is_stressed_vowel() {
+                return &self.nucleus[offset..];
+            }
+            last = Some(offset);
+        }
+        last.map_or("", |offset| &self.nucleus[offset..])
+    }
+
+    /// Copies the syllable into an owned one, composing decomposed accents.
+    pub fn to_syllable(&self) -> Syllable {
+        let owned = |s: &str| composed(s).map(|(_, c)| c).collect::<String>();
+        Syllable {
+            onset: owned(self.onset),
+            nucleus: owned(self.nucleus),
+            coda: owned(self.coda),
+        }
+    }
+}
+
+impl Parts for SyllableRef<'_> {
+    fn nucleus(&self) -> &str {
+        self.nucleus
+    }
+
+    fn coda(&self) -> &str {
+        self.coda
+    }
+
+    fn has_accented_vowel(&self) -> bool {
+        SyllableRef::has_accented_vowel(self)
+    }
+
+    fn nucleus_len(&self) -> usize {
+        composed(self.nucleus).count()
+    }
+}
+
+impl Display for SyllableRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}{}", self.onset, self.nucleus, self.coda)
+    }
+}
+
+/// A parsed word borrowing its syllables from the input.
+///
+/// # Example
+///
+/// ```
+/// use syllabize_es::word_ref::WordRef;
+/// let word = WordRef::parse("canción").unwrap();
+/// assert_eq!(word.syllables[1].nucleus, "ió");
+/// assert_eq!(word.boundaries().collect::<Vec<usize>>(), vec![3]);
+/// assert_eq!(word.rhyme(), "ón");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordRef<'a> {
+    pub source: &'a str,
+    pub syllables: Vec<SyllableRef<'a>>,
+    pub stress_index: usize,
+}
+
+impl<'a> WordRef<'a> {
+    /// Parses a word, rejecting input that can't be syllabized, same as
+    /// parsing a [`Word`].
+    pub fn parse(source: &'a str) -> Result<WordRef<'a>, ParseError> {
+        let (chars, _) = composed_chars(source);
+        let bounds = checked_bounds(source, &chars)?;
+        Ok(WordRef::new(source, &bounds))
+    }
+
+    fn new(source: &'a str, bounds: &[Bounds]) -> WordRef<'a> {
+        // Byte offset of the end of each composed character.
+        let mut ends = composed(source)
+            .map(|(b, _)| b)
+            .skip(1)
+            .chain(iter::once(source.len()));
+        let mut current = 0;
+        let mut advance = |n: usize| {
+            if n > 0 {
+                current = ends.nth(n - 1).unwrap_or(source.len());
+            }
+            current
+        };
+        let mut start = 0;
+        let syllables = bounds
+            .iter()
+            .map(|b| {
+                let onset_end = advance(b.onset);
+                let nucleus_end = advance(b.nucleus);
+                let end = advance(b.coda);
+                let syllable = SyllableRef {
+                    onset: &source[start..onset_end],
+                    nucleus: &source[onset_end..nucleus_end],
+                    coda: &source[nucleus_end..end],
+                    bytes: start..end,
+                };
+                start = end;
+                syllable
+            })
+            .collect::<Vec<SyllableRef<'a>>>();
+        let stress_index = identify_stress(&syllables);
+        WordRef {
+            source,
+            syllables,
+            stress_index,
+        }
+    }
+
+    /// Byte offsets in `source` where one syllable ends and the next begins.
+    pub fn boundaries(&self) -> impl Iterator<Item = usize> + '_ {
+        self.syllables.iter().skip(1).map(|s| s.bytes.start)
+    }
+
+    /// The rhyming part of the word, from the stressed vowel to the end.
+    pub fn rhyme(&self) -> &'a str {
+        let Some(stress_syllable) = self.syllables.get(self.stress_index) else {
+            return "";
+        };
+        let vowels = stress_syllable.vowels_since_stress();
+        let nucleus_end = stress_syllable.bytes.start
+            + stress_syllable.onset.len()
+            + stress_syllable.nucleus.len();
+        &self.source[nucleus_end - vowels.len()..]
+    }
+
+    /// Returns the type of stress, or `None` if the word has no syllables.
+    pub fn stress(&self) -> Option<StressType> {
+        stress_type(self.syllables.len(), self.stress_index)
+    }
+
+    pub fn syllabize(&self, delimiter: &str) -> String {
+        self.syllables
+            .iter()
+            .map(|s| &self.source[s.bytes.clone()])
+            .collect::<Vec<&str>>()
+            .join(delimiter)
+    }
+
+    /// Converts into an owned [`Word`].
+    pub fn to_word(&self) -> Word {
+        Word::from(self)
+    }
+}
+
+/// Lenient conversion, invalid words end up with no syllables.
+impl<'a> From<&'a str> for WordRef<'a> {
+    fn from(source: &'a str) -> Self {
+        let mut bounds = Vec::with_capacity(16);
+        if automaton::split(composed(source).map(|(_, c)| c), &mut bounds).is_err() {
+            bounds.clear();
+        }
+        WordRef::new(source, &bounds)
+    }
+}
+
+impl From<&WordRef<'_>> for Word {
+    fn from(word: &WordRef<'_>) -> Word {
+        let (chars, normalized) = composed_chars(word.source);
+        let bounds = word
+            .syllables
+            .iter()
+            .map(|s| Bounds {
+                onset: composed(s.onset).count(),
+                nucleus: composed(s.nucleus).count(),
+                coda: composed(s.coda).count(),
+            })
+            .collect::<Vec<Bounds>>();
+        Word::new(word.source, &chars, &bounds, normalized)
+    }
+}
+
+impl Display for WordRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let end = self.syllables.last().map_or(0, |s| s.bytes.end);
+        write!(f, "{}", &self.source[..end])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test::Bencher;
+
+    #[test]
+    fn test_parse() {
+        let word = WordRef::parse("envergadura").unwrap();
+        assert_eq!(word.syllabize("-"), "en-ver-ga-du-ra");
+        assert_eq!(word.stress(), Some(StressType::Paroxytone));
+        assert_eq!(word.rhyme(), "ura");
+        assert_eq!(
+            WordRef::parse("nn"),
+            Err(ParseError::NoNucleus { offset: 0 })
+        );
+        assert!(WordRef::from("nn").syllables[0].nucleus.is_empty());
+    }
+
+    #[test]
+    fn test_decomposed() {
+        let word = WordRef::from("cancio\u{301}n");
+        assert_eq!(word.syllables[1].nucleus, "io\u{301}");
+        assert_eq!(word.stress_index, 1);
+        assert_eq!(word.rhyme(), "o\u{301}n");
+        assert_eq!(word.to_word().syllables[1].nucleus, "ió");
+    }
+
+    #[test]
+    fn test_to_word() {
+        for s in ["construir", "Paraguay", "azahar", "ahí", "guapo"] {
+            let word = WordRef::from(s).to_word();
+            let expected = Word::from(s);
+            assert_eq!(word.syllables, expected.syllables);
+            assert_eq!(word.stress_index, expected.stress_index);
+            assert_eq!(word.spans, expected.spans);
+        }
+    }
+
+    #[bench]
+    fn bench_wordify_ref(b: &mut Bencher) {
+        b.iter(|| {
+            let _word = WordRef::from("envergadura");
+        });
+    }
+
+    #[bench]
+    fn bench_rhyme_ref(b: &mut Bencher) {
+        let word = WordRef::from("envergadura");
+        b.iter(|| word.rhyme());
+    }
+}
@@ -0,0 +1,86 @@
+use syllabize_es::diacritic::{check_text, classify, Context, Role};
+
+fn suggestions(text: &str) -> Vec<(String, Context)> {
+    check_text(text)
+        .into_iter()
+        .map(|h| (h.suggestion, h.context))
+        .collect()
+}
+
+#[test]
+fn roles() {
+    let data = [
+        ("el", Role::Article, false),
+        ("él", Role::Pronoun, true),
+        ("tu", Role::Possessive, false),
+        ("tú", Role::Pronoun, true),
+        ("té", Role::Noun, true),
+        ("sé", Role::Verb, true),
+        ("mas", Role::Conjunction, false),
+        ("más", Role::Adverb, true),
+        ("que", Role::Relative, false),
+        ("Qué", Role::Interrogative, true),
+        ("DÓNDE", Role::Interrogative, true),
+    ];
+    for (word, role, tonic) in data {
+        let entry = classify(word).unwrap();
+        assert_eq!(entry.role, role, "{}", word);
+        assert_eq!(entry.tonic, tonic, "{}", word);
+    }
+    assert_eq!(classify("fue"), None);
+}
+
+#[test]
+fn questions() {
+    assert_eq!(
+        suggestions("¿Que hora es?"),
+        [("Qué".to_string(), Context::Question)]
+    );
+    assert_eq!(
+        suggestions("Dime, ¿por que lo hiciste?"),
+        [("qué".to_string(), Context::Question)]
+    );
+    assert_eq!(
+        suggestions("¿Y que?"),
+        [("qué".to_string(), Context::Question)]
+    );
+    assert_eq!(
+        suggestions("¿Pero como lo sabes?"),
+        [("cómo".to_string(), Context::Question)]
+    );
+    assert_eq!(
+        suggestions("¿Y de quien es?"),
+        [("quién".to_string(), Context::Question)]
+    );
+    assert_eq!(
+        suggestions("¡Como llueve!"),
+        [("Cómo".to_string(), Context::Exclamation)]
+    );
+    // Only the word opening the question is flagged.
+    assert_eq!(suggestions("¿Crees que vendrá?"), []);
+    assert_eq!(suggestions("¿Qué hora es?"), []);
+}
+
+#[test]
+fn indirect_questions() {
+    assert_eq!(
+        suggestions("No sé que hacer."),
+        [("qué".to_string(), Context::IndirectQuestion)]
+    );
+    assert_eq!(
+        suggestions("Me pregunto donde estará."),
+        [("dónde".to_string(), Context::IndirectQuestion)]
+    );
+    assert_eq!(suggestions("Sé que vendrá."), []);
+    assert_eq!(suggestions("No lo sé, cuando puedas me dices."), []);
+}
+
+#[test]
+fn offsets() {
+    let text = "¿Quien sabe? ¿Cual?";
+    let hints = check_text(text);
+    assert_eq!(hints.len(), 2);
+    for hint in hints {
+        assert_eq!(&text[hint.offset..hint.offset + hint.word.len()], hint.word);
+    }
+}
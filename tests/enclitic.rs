@@ -0,0 +1,57 @@
+use syllabize_es::enclitic::{attach_enclitics, split_enclitics};
+
+const DATA: &[(&str, &str, &[&str])] = &[
+    ("dámelo", "da", &["me", "lo"]),
+    ("dímelo", "di", &["me", "lo"]),
+    ("cómetelo", "come", &["te", "lo"]),
+    ("diciéndoselo", "diciendo", &["se", "lo"]),
+    ("tráiganosla", "traiga", &["nos", "la"]),
+    ("tráiganlo", "traigan", &["lo"]),
+    ("decírselo", "decir", &["se", "lo"]),
+    ("decirlo", "decir", &["lo"]),
+    ("verte", "ver", &["te"]),
+    ("oírlo", "oír", &["lo"]),
+    ("haciéndolo", "haciendo", &["lo"]),
+    ("decidlo", "decid", &["lo"]),
+    ("decíos", "decid", &["os"]),
+    ("amaos", "amad", &["os"]),
+    ("reuníos", "reunid", &["os"]),
+    ("idos", "id", &["os"]),
+    ("vámonos", "vamos", &["nos"]),
+    ("digámoselo", "digamos", &["se", "lo"]),
+];
+
+#[test]
+fn split() {
+    for (word, verb, clitics) in DATA {
+        let split = split_enclitics(word).unwrap_or_else(|| panic!("{}", word));
+        assert_eq!(split.verb, *verb, "{}", word);
+        assert_eq!(split.clitics, *clitics, "{}", word);
+    }
+}
+
+#[test]
+fn attach() {
+    for (word, verb, clitics) in DATA {
+        assert_eq!(attach_enclitics(verb, clitics).unwrap(), *word, "{}", verb);
+    }
+    assert_eq!(attach_enclitics("da", &["me"]).unwrap(), "dame");
+    assert_eq!(attach_enclitics("dé", &["me"]).unwrap(), "deme");
+    assert_eq!(attach_enclitics("haz", &["lo"]).unwrap(), "hazlo");
+}
+
+#[test]
+fn not_enclitic() {
+    for word in [
+        "pala", "parte", "fuerte", "Carlos", "caos", "Dios", "casa", "perla", "nos", "te",
+    ] {
+        assert_eq!(split_enclitics(word), None, "{}", word);
+    }
+}
+
+#[test]
+fn wrong_order() {
+    assert_eq!(attach_enclitics("da", &["lo", "me"]), None);
+    assert_eq!(attach_enclitics("di", &["me", "se"]), None);
+    assert_eq!(attach_enclitics("di", &["lo", "lo"]), None);
+}
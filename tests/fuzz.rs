@@ -0,0 +1,213 @@
+use syllabize_es::char_util::*;
+use syllabize_es::enclitic::split_enclitics;
+use syllabize_es::lint::{fix, lint};
+use syllabize_es::plural::pluralize;
+use syllabize_es::rules::SyllabizeRules;
+use syllabize_es::str_util::*;
+use syllabize_es::syllable::Syllable;
+use syllabize_es::word_ref::WordRef;
+use syllabize_es::{
+    count_syllables, equal_onset, stress_position, Dialect, RhymeOptions, SplitMode,
+    SyllabizeOptions, Word,
+};
+
+/// Characters that exercise the special cases of the parser, mixed with
+/// arbitrary code points below.
+const ALPHABET: &[char] = &[
+    'a', 'e', 'i', 'o', 'u', 'á', 'é', 'í', 'ó', 'ú', 'ü', 'A', 'E', 'I', 'O', 'U', 'Á', 'É', 'Í',
+    'Ó', 'Ú', 'Ü', 'y', 'Y', 'h', 'H', 'q', 'Q', 'g', 'G', 'c', 'l', 'r', 's', 'n', 'ñ', 'Ñ', 't',
+    'b', 'p', 'x', 'z', '-', ' ', '\u{301}', '\u{308}',
+];
+
+/// A small xorshift generator, so the harness needs no dependencies and
+/// failures are reproducible from the seed.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn char(&mut self) -> char {
+        if self.below(8) == 0 {
+            loop {
+                if let Some(c) = char::from_u32(self.below(0x11_0000) as u32) {
+                    return c;
+                }
+            }
+        }
+        ALPHABET[self.below(ALPHABET.len())]
+    }
+
+    fn string(&mut self, max_len: usize) -> String {
+        let len = self.below(max_len + 1);
+        (0..len).map(|_| self.char()).collect()
+    }
+}
+
+fn options(rng: &mut Rng) -> RhymeOptions {
+    RhymeOptions {
+        yeismo: rng.below(2) == 0,
+        seseo: rng.below(2) == 0,
+        b_equals_v: rng.below(2) == 0,
+    }
+}
+
+fn exercise_word(word: &Word, other: &Word, rng: &mut Rng) {
+    let _ = word.syllabize("-");
+    let _ = word.to_string();
+    let _ = word.rhyme();
+    let _ = word.stress();
+    let _ = word.accent();
+    let _ = word.check_tilde(None);
+    let _ = word.check_tilde(Some(rng.below(4)));
+    let _ = word.with_stress(rng.below(4));
+    let _ = word.stresses();
+    let _ = word.strip_accents().map(|s| s.restore());
+    let _ = word.vowel_combos();
+    let _ = word.rhymes_with(other, None);
+    let _ = word.rhymes_with(other, Some(options(rng)));
+    let _ = word.assonant_rhymes_with(other);
+    for a in &word.syllables {
+        let _ = a.has_accented_vowel();
+        let _ = a.vowels_since_stress();
+        for b in &other.syllables {
+            let _ = equal_onset(a, b, &options(rng));
+        }
+    }
+}
+
+#[test]
+fn random_words() {
+    let mut rng = Rng(0x5eed_1e55_ba5e_ba11);
+    let mut rules = "cluster tz\nseparate tl\ncluster sk\nword Wind-sor"
+        .parse::<SyllabizeRules>()
+        .unwrap();
+    rules.lexicon = "Face-book 0\nJer-sey 0\nbi\u{307}-a 0".parse().unwrap();
+    // 'İ' lowercases to two chars, "i\u{307}".
+    if let Ok(word) = Word::parse_with("bİa", &rules) {
+        assert_eq!(word.to_string(), "bİa");
+    }
+    for _ in 0..20_000 {
+        let s = rng.string(12);
+        let t = rng.string(12);
+        let word = Word::from(s.as_str());
+        let other = Word::from(t.as_str());
+        if !word.syllables.is_empty() {
+            // Syllables cover the whole input, in order.
+            assert_eq!(word.to_string(), normalize(&s), "{:?}", s);
+            for (syllable, span) in word.syllables.iter().zip(&word.spans) {
+                assert_eq!(
+                    syllable.to_string(),
+                    normalize(&s[span.syllable.bytes.clone()])
+                );
+            }
+        }
+        exercise_word(&word, &other, &mut rng);
+        let word_ref = WordRef::from(s.as_str());
+        assert_eq!(word_ref.stress_index, word.stress_index);
+        assert_eq!(normalize(word_ref.rhyme()), word.rhyme());
+        assert_eq!(word_ref.stress(), word.stress());
+        let _ = word_ref.syllabize("-");
+        let _ = pluralize(&s);
+        let _ = split_enclitics(&s);
+        let morphological = SyllabizeOptions::default().with_mode(SplitMode::Morphological);
+        if let Ok(word) = Word::parse_with_options(&s, morphological) {
+            assert_eq!(word.to_string(), normalize(&s));
+        }
+        let peninsular = SyllabizeOptions::default().with_dialect(Dialect::Peninsular);
+        if let Ok(word) = Word::parse_with_options(&s, peninsular) {
+            assert_eq!(word.to_string(), normalize(&s));
+            let len = word.to_string().chars().count();
+            assert!(word.hyphenation_points().iter().all(|&i| 0 < i && i < len));
+        }
+        let loanwords = SyllabizeOptions::default().with_loanwords(true);
+        if let Ok(word) = Word::parse_with_options(&s, loanwords) {
+            assert_eq!(word.to_string(), normalize(&s));
+            assert!(word.syllables.iter().all(|s| !s.nucleus.is_empty()));
+            for span in &word.foreign_graphemes {
+                assert!(!s[span.bytes.clone()].is_empty());
+            }
+        }
+        if let Ok(word) = Word::parse_with(&s, &rules) {
+            assert_eq!(word.to_string(), normalize(&s));
+        }
+        let _ = fix(&s, &lint(&s));
+        if let Ok(parsed) = s.parse::<Word>() {
+            exercise_word(&parsed, &word, &mut rng);
+        }
+    }
+}
+
+#[test]
+fn counts_agree_with_word() {
+    let mut rng = Rng(0xc0de_7ab1_e5ea_5e11);
+    for _ in 0..100_000 {
+        let s = rng.string(12);
+        let word = Word::from(s.as_str());
+        assert_eq!(count_syllables(&s), word.syllables.len(), "{:?}", s);
+        assert_eq!(stress_position(&s), word.stress_index, "{:?}", s);
+    }
+}
+
+#[test]
+fn random_syllables() {
+    let mut rng = Rng(0xdead_beef_cafe_f00d);
+    for _ in 0..20_000 {
+        let a = Syllable {
+            onset: rng.string(3),
+            nucleus: rng.string(3),
+            coda: rng.string(3),
+        };
+        let b = Syllable {
+            onset: rng.string(3),
+            nucleus: rng.string(3),
+            coda: rng.string(3),
+        };
+        let _ = a.has_accented_vowel();
+        let _ = a.vowels_since_stress();
+        let _ = equal_onset(&a, &b, &options(&mut rng));
+        let word = Word {
+            syllables: vec![a, b],
+            stress_index: rng.below(4),
+            normalized: false,
+            spans: vec![],
+            mode: SplitMode::default(),
+            dialect: Dialect::default(),
+            loanwords: false,
+            foreign_graphemes: vec![],
+        };
+        exercise_word(&word, &Word::from(""), &mut rng);
+    }
+}
+
+#[test]
+fn random_helpers() {
+    let mut rng = Rng(0x0123_4567_89ab_cdef);
+    for _ in 0..20_000 {
+        let (a, b, c) = (rng.char(), rng.char(), rng.char());
+        let _ = a.is_vowel();
+        let _ = a.is_weak_vowel();
+        let _ = a.is_stressed_vowel();
+        let _ = a.is_accented_vowel();
+        let _ = a.is_soft_c_trigger();
+        let _ = is_letter(a);
+        let _ = combo_type(a, b);
+        let _ = can_form_hiatus(a, b);
+        let _ = can_form_triphthong(a, b, c);
+        let s = rng.string(4);
+        let t = rng.string(4);
+        let _ = is_consonant_group(&s);
+        let _ = stress_index(&s);
+        let _ = loose_match(&s, &t);
+        let _ = is_both_s_or_z(&s, &t);
+        let _ = is_both_b_or_v(&s, &t);
+    }
+}
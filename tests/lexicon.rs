@@ -0,0 +1,108 @@
+use syllabize_es::lexicon::{Exception, Lexicon, LexiconError};
+use syllabize_es::rules::SyllabizeRules;
+use syllabize_es::{StressType, Word};
+
+const LEXICON: &str = "
+# Brand names
+Face-book 0 /féisbuk/
+# Loanwords and proper nouns
+Jer-sey 0 /yérsei/
+Wind-sor 0
+Mé-xi-co 0 'x' sounds like 'j'
+";
+
+fn rules() -> SyllabizeRules {
+    SyllabizeRules {
+        lexicon: LEXICON.parse::<Lexicon>().unwrap(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn consulted_first() {
+    let rules = rules();
+    for (word, syllables, stress_index) in [
+        ("Facebook", "Face-book", 0),
+        ("Jersey", "Jer-sey", 0),
+        ("WINDSOR", "WIND-SOR", 0),
+        ("México", "Mé-xi-co", 0),
+    ] {
+        let word = Word::parse_with(word, &rules).unwrap();
+        assert_eq!(word.syllabize("-"), syllables);
+        assert_eq!(word.stress_index, stress_index, "{}", word);
+    }
+    assert_eq!(Word::from("Jersey").stress(), Some(StressType::Oxytone));
+    assert_eq!(
+        Word::parse_with("Jersey", &rules).unwrap().stress(),
+        Some(StressType::Paroxytone)
+    );
+    assert_eq!(Word::from("Windsor").syllabize("-"), "Win-dsor");
+}
+
+#[test]
+fn before_rules() {
+    let mut rules = "word Fa-ce-book".parse::<SyllabizeRules>().unwrap();
+    assert_eq!(
+        Word::parse_with("Facebook", &rules).unwrap().syllabize("-"),
+        "Fa-ce-book"
+    );
+    rules.lexicon = LEXICON.parse().unwrap();
+    assert_eq!(
+        Word::parse_with("Facebook", &rules).unwrap().syllabize("-"),
+        "Face-book"
+    );
+}
+
+#[test]
+fn notes() {
+    let lexicon = LEXICON.parse::<Lexicon>().unwrap();
+    assert_eq!(lexicon.get("mexico").and_then(|e| e.note.as_deref()), None);
+    assert_eq!(
+        lexicon.get("méxico").and_then(|e| e.note.as_deref()),
+        Some("'x' sounds like 'j'")
+    );
+    assert_eq!(lexicon.get("Windsor").unwrap().note, None);
+    assert_eq!(lexicon.get("Windsor").unwrap().spelling(), "Windsor");
+}
+
+#[test]
+fn multiple_words() {
+    let mut lexicon = Lexicon::default();
+    assert!(lexicon.insert(Exception {
+        syllables: vec!["Nue".to_string(), "va Jer".to_string(), "sey".to_string(),],
+        stress_index: 2,
+        note: Some("/nuéba yérsei/".to_string()),
+    }));
+    let text = lexicon.to_string();
+    assert_eq!(text, "Nue-va_Jer-sey 2 /nuéba yérsei/\n");
+    assert_eq!(text.parse::<Lexicon>(), Ok(lexicon.clone()));
+    let rules = SyllabizeRules {
+        lexicon,
+        ..Default::default()
+    };
+    let word = Word::parse_with("nueva jersey", &rules).unwrap();
+    assert_eq!(word.syllabize("-"), "nue-va jer-sey");
+    assert_eq!(word.stress_index, 2);
+}
+
+#[test]
+fn plain_text() {
+    let mut lexicon = LEXICON.parse::<Lexicon>().unwrap();
+    assert!(lexicon.insert(Exception {
+        syllables: vec!["Whats".to_string(), "App".to_string()],
+        stress_index: 0,
+        note: Some("/guásap/".to_string()),
+    }));
+    assert!(!lexicon.insert(Exception {
+        syllables: vec!["Jer".to_string(), "sey".to_string()],
+        stress_index: 0,
+        note: Some("/yérsei/\nWhats-App 1".to_string()),
+    }));
+    let text = lexicon.to_string();
+    assert!(text.ends_with("Mé-xi-co 0 'x' sounds like 'j'\nWhats-App 0 /guásap/\n"));
+    assert_eq!(text.parse::<Lexicon>(), Ok(lexicon));
+    assert_eq!(
+        "Face-book 2".parse::<Lexicon>(),
+        Err(LexiconError::InvalidStress { line: 1 })
+    );
+}
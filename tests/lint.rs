@@ -0,0 +1,115 @@
+use syllabize_es::lint::{fix, lint, LintKind};
+
+fn check(data: &[(&str, LintKind, &str)]) {
+    for (word, kind, fixed) in data {
+        let lints = lint(word);
+        assert_eq!(lints.len(), 1, "{}", word);
+        assert_eq!(lints[0].kind, *kind, "{}", word);
+        assert_eq!(fix(word, &lints), *fixed);
+    }
+}
+
+fn clean(words: &[&str]) {
+    for word in words {
+        assert_eq!(lint(word), [], "{}", word);
+    }
+}
+
+#[test]
+fn diaeresis() {
+    check(&[
+        ("pinguino", LintKind::Diaeresis, "pingüino"),
+        ("verguenza", LintKind::Diaeresis, "vergüenza"),
+        ("bilingue", LintKind::Diaeresis, "bilingüe"),
+        ("agüa", LintKind::Diaeresis, "agua"),
+        ("lengüa", LintKind::Diaeresis, "lengua"),
+    ]);
+    clean(&["pingüino", "guerra", "guitarra", "agua", "antigüedad"]);
+}
+
+#[test]
+fn q() {
+    check(&[
+        ("quando", LintKind::Q, "cuando"),
+        ("quota", LintKind::Q, "cuota"),
+        ("qasa", LintKind::Q, "casa"),
+        ("qeso", LintKind::Q, "queso"),
+        ("qien", LintKind::Q, "quien"),
+    ]);
+    clean(&["queso", "quiero", "quórum", "Qatar", "cuento", "cuidar"]);
+}
+
+#[test]
+fn z_before_e_or_i() {
+    check(&[
+        ("zero", LintKind::ZBeforeEOrI, "cero"),
+        ("empezé", LintKind::ZBeforeEOrI, "empecé"),
+        ("Zielo", LintKind::ZBeforeEOrI, "Cielo"),
+    ]);
+    clean(&["zapato", "zigzag", "zinc", "nazi", "zeta", "zen"]);
+}
+
+#[test]
+fn n_before_p_or_b() {
+    check(&[
+        ("canpo", LintKind::NBeforePOrB, "campo"),
+        ("enbarazo", LintKind::NBeforePOrB, "embarazo"),
+        ("inposible", LintKind::NBeforePOrB, "imposible"),
+    ]);
+    clean(&["campo", "ambos", "Canberra", "envío"]);
+}
+
+#[test]
+fn r() {
+    check(&[
+        ("antirobo", LintKind::R, "antirrobo"),
+        ("contrarevolución", LintKind::R, "contrarrevolución"),
+        ("vicerector", LintKind::R, "vicerrector"),
+        ("honrra", LintKind::R, "honra"),
+        ("alrrededor", LintKind::R, "alrededor"),
+        ("Isrrael", LintKind::R, "Israel"),
+    ]);
+    clean(&[
+        "antirrobo",
+        "contrario",
+        "autoridad",
+        "Enrique",
+        "pero",
+        "perro",
+    ]);
+}
+
+#[test]
+fn hard_c() {
+    check(&[
+        ("keso", LintKind::HardC, "queso"),
+        ("kien", LintKind::HardC, "quien"),
+        ("KESO", LintKind::HardC, "QUESO"),
+        ("kasa", LintKind::HardC, "casa"),
+        ("kuchara", LintKind::HardC, "cuchara"),
+    ]);
+    clean(&[
+        "kilo", "kiosko", "kiwi", "koala", "casa", "cine", "inbox", "whisky", "punk",
+    ]);
+}
+
+#[test]
+fn offsets() {
+    let text = "¿Dónde está el pinguino? En el canpo.";
+    let lints = lint(text);
+    assert_eq!(lints.len(), 2);
+    assert_eq!(&text[lints[0].span.bytes.clone()], "u");
+    assert_eq!(lints[0].span.chars, 19..20);
+    assert_eq!(&text[lints[1].span.bytes.clone()], "n");
+    assert_eq!(fix(text, &lints), "¿Dónde está el pingüino? En el campo.");
+}
+
+#[test]
+fn decomposed_accents() {
+    let text = "la cancio\u{301}n del pinguino";
+    let lints = lint(text);
+    assert_eq!(lints.len(), 1);
+    assert_eq!(lints[0].kind, LintKind::Diaeresis);
+    assert_eq!(lints[0].span.chars, 20..21);
+    assert!(lint("cancio\u{301}n").is_empty());
+}
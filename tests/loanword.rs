@@ -0,0 +1,56 @@
+use syllabize_es::{SyllabizeOptions, Word};
+
+fn loanword(word: &str) -> Word {
+    let options = SyllabizeOptions::default().with_loanwords(true);
+    Word::parse_with_options(word, options).unwrap()
+}
+
+#[test]
+fn graphemes_as_units() {
+    for (word, native, foreign) in [
+        ("kiwi", "ki-wi", "ki-wi"),
+        ("sándwich", "sán-dwich", "sánd-wich"),
+        ("show", "show", "show"),
+        ("Quetzalcóatl", "Quet-zal-có-atl", "Que-tzal-có-atl"),
+        ("hockey", "hoc-key", "ho-ckey"),
+        ("whisky", "whis-ky", "whis-ky"),
+        ("Washington", "Was-hin-gton", "Wa-shing-ton"),
+        ("Birmingham", "Bir-min-gham", "Bir-ming-ham"),
+        ("Ashley", "As-hley", "Ash-ley"),
+        ("cowboy", "cow-boy", "cow-boy"),
+        ("casa", "ca-sa", "ca-sa"),
+    ] {
+        assert_eq!(Word::from(word).syllabize("-"), native);
+        assert_eq!(loanword(word).syllabize("-"), foreign);
+    }
+}
+
+#[test]
+fn w_as_semivowel() {
+    let word = loanword("show");
+    assert_eq!(word.syllables[0].onset, "sh");
+    assert_eq!(word.syllables[0].nucleus, "ow");
+    assert_eq!(word.syllables[0].coda, "");
+    assert_eq!(loanword("cowboy").syllables[0].nucleus, "ow");
+    assert_eq!(loanword("kiwi").syllables[1].onset, "w");
+    assert_eq!(Word::from("show").syllables[0].coda, "w");
+}
+
+#[test]
+fn marks_foreign_graphemes() {
+    let word = loanword("Quetzalcóatl");
+    assert!(word.loanwords);
+    let graphemes = word
+        .foreign_graphemes
+        .iter()
+        .map(|s| &"Quetzalcóatl"[s.bytes.clone()])
+        .collect::<Vec<_>>();
+    assert_eq!(graphemes, ["tz"]);
+    assert_eq!(loanword("hockey").foreign_graphemes.len(), 1);
+    assert_eq!(loanword("kick").foreign_graphemes.len(), 2);
+    assert!(loanword("casa").foreign_graphemes.is_empty());
+    assert!(Word::from("kiwi").foreign_graphemes.is_empty());
+    let word = loanword("sa\u{301}ndwich");
+    assert_eq!(word.foreign_graphemes[0].chars, 5..6);
+    assert!(word.with_stress(1).unwrap().loanwords);
+}
@@ -0,0 +1,91 @@
+use syllabize_es::{ParseError, Word};
+
+#[test]
+fn valid() {
+    let word: Word = "canción".parse().unwrap();
+    assert_eq!(word.syllabize("-"), "can-ción");
+    assert!("ah".parse::<Word>().is_ok());
+}
+
+#[test]
+fn empty() {
+    assert_eq!("".parse::<Word>().unwrap_err(), ParseError::Empty);
+}
+
+#[test]
+fn incomplete_onset() {
+    let cases = [("q", 0), ("g", 0), ("gu", 0), ("qu", 0)];
+    for (s, offset) in cases {
+        assert_eq!(
+            s.parse::<Word>().unwrap_err(),
+            ParseError::IncompleteOnset { offset }
+        );
+    }
+}
+
+#[test]
+fn no_nucleus() {
+    let cases = [("nn", 0), ("hmm", 0)];
+    for (s, offset) in cases {
+        assert_eq!(
+            s.parse::<Word>().unwrap_err(),
+            ParseError::NoNucleus { offset }
+        );
+    }
+}
+
+#[test]
+fn unsupported_character() {
+    assert_eq!(
+        "año2".parse::<Word>().unwrap_err(),
+        ParseError::UnsupportedCharacter {
+            character: '2',
+            offset: 3
+        }
+    );
+    assert_eq!(
+        "sí!".parse::<Word>().unwrap_err(),
+        ParseError::UnsupportedCharacter {
+            character: '!',
+            offset: 2
+        }
+    );
+}
+
+#[test]
+fn lenient_from() {
+    let word: Word = "q".into();
+    assert!(word.syllables.is_empty());
+}
+
+#[test]
+fn decomposed_accents() {
+    let word: Word = "cancio\u{301}n".parse().unwrap();
+    assert!(word.normalized);
+    assert_eq!(word.syllabize("-"), "can-ción");
+    assert_eq!(word.stress_index, 1);
+
+    let word = Word::from("pingu\u{308}ino");
+    assert!(word.normalized);
+    assert_eq!(word.syllabize("-"), "pin-güi-no");
+
+    let word = Word::from("an\u{303}o");
+    assert_eq!(word.syllabize("-"), "a-ño");
+
+    assert!(!Word::from("canción").normalized);
+}
+
+#[test]
+fn unsupported_combining_mark() {
+    assert_eq!(
+        "a\u{301}ra\u{300}".parse::<Word>().unwrap_err(),
+        ParseError::UnsupportedCharacter {
+            character: '\u{300}',
+            offset: 4
+        }
+    );
+    assert_eq!(
+        "n\u{303}gu".parse::<Word>().unwrap_err(),
+        ParseError::IncompleteOnset { offset: 2 }
+    );
+}
@@ -0,0 +1,86 @@
+use syllabize_es::plural::pluralize;
+
+fn check(data: &[(&str, &str)]) {
+    for (singular, plural) in data {
+        assert_eq!(pluralize(singular).unwrap(), *plural, "{}", singular);
+    }
+}
+
+#[test]
+fn vowels() {
+    check(&[
+        ("casa", "casas"),
+        ("taxi", "taxis"),
+        ("tribu", "tribus"),
+        ("café", "cafés"),
+        ("sofá", "sofás"),
+        ("dominó", "dominós"),
+        ("rubí", "rubíes"),
+        ("tabú", "tabúes"),
+        ("menú", "menús"),
+    ]);
+}
+
+#[test]
+fn consonants() {
+    check(&[
+        ("árbol", "árboles"),
+        ("mujer", "mujeres"),
+        ("pared", "paredes"),
+        ("reloj", "relojes"),
+        ("luz", "luces"),
+        ("lápiz", "lápices"),
+        ("rey", "reyes"),
+        ("virrey", "virreyes"),
+        ("pony", "ponis"),
+        ("robot", "robots"),
+        ("cómic", "cómics"),
+        ("sándwich", "sándwiches"),
+        ("SÁNDWICH", "SÁNDWICHES"),
+    ]);
+}
+
+#[test]
+fn accent_shifts() {
+    check(&[
+        ("joven", "jóvenes"),
+        ("examen", "exámenes"),
+        ("orden", "órdenes"),
+        ("canción", "canciones"),
+        ("alemán", "alemanes"),
+        ("autobús", "autobuses"),
+        ("país", "países"),
+        ("interés", "intereses"),
+    ]);
+}
+
+#[test]
+fn invariable() {
+    check(&[
+        ("crisis", "crisis"),
+        ("lunes", "lunes"),
+        ("tórax", "tórax"),
+        ("bíceps", "bíceps"),
+    ]);
+}
+
+#[test]
+fn monosyllables() {
+    check(&[
+        ("mes", "meses"),
+        ("tos", "toses"),
+        ("fax", "faxes"),
+        ("sol", "soles"),
+    ]);
+}
+
+#[test]
+fn exceptions() {
+    check(&[
+        ("carácter", "caracteres"),
+        ("régimen", "regímenes"),
+        ("espécimen", "especímenes"),
+        ("álbum", "álbumes"),
+        ("jersey", "jerséis"),
+    ]);
+}
@@ -0,0 +1,81 @@
+use syllabize_es::accent::{Tilde, TildeError};
+use syllabize_es::reform::{check_reform, Orthography, ReformKind, HIATUS_MONOSYLLABLES};
+use syllabize_es::Word;
+
+#[test]
+fn monosyllables_2010() {
+    for word in HIATUS_MONOSYLLABLES {
+        let word = Word::from(*word);
+        assert_eq!(word.syllables.len(), 1, "{}", word);
+        let check = word.check_tilde_in(None, Orthography::Rae2010).unwrap();
+        assert_eq!(check.tilde, Tilde::Forbidden, "{}", word);
+        assert_eq!(check.error, None, "{}", word);
+    }
+    for (old, new) in [
+        ("guión", "guion"),
+        ("truhán", "truhan"),
+        ("fié", "fie"),
+        ("liáis", "liais"),
+        ("huí", "hui"),
+    ] {
+        let check = Word::from(old).check_tilde(None).unwrap();
+        assert_eq!(check.error, Some(TildeError::Superfluous), "{}", old);
+        assert_eq!(check.suggestion, new);
+    }
+}
+
+#[test]
+fn monosyllables_1999() {
+    for word in ["guion", "guión", "truhán", "fie", "huí", "liáis"] {
+        let check = Word::from(word)
+            .check_tilde_in(None, Orthography::Rae1999)
+            .unwrap();
+        assert_eq!(check.tilde, Tilde::Optional, "{}", word);
+        assert_eq!(check.error, None, "{}", word);
+        assert_eq!(check.suggestion, word);
+    }
+    // Other monosyllables never took a tilde.
+    let check = Word::from("fué")
+        .check_tilde_in(None, Orthography::Rae1999)
+        .unwrap();
+    assert_eq!(check.error, Some(TildeError::Superfluous));
+}
+
+#[test]
+fn validator() {
+    let text = "Éste es el guión; ésa no. Sólo quedan 2 ó 3 páginas y el truhán se rió.";
+    let hints = check_reform(text);
+    let found = hints
+        .iter()
+        .map(|h| (h.word.as_str(), h.suggestion.as_str(), h.kind))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        found,
+        [
+            ("Éste", "Este", ReformKind::Demonstrative),
+            ("guión", "guion", ReformKind::HiatusMonosyllable),
+            ("ésa", "esa", ReformKind::Demonstrative),
+            ("Sólo", "Solo", ReformKind::Solo),
+            ("ó", "o", ReformKind::DisjunctiveO),
+            ("truhán", "truhan", ReformKind::HiatusMonosyllable),
+            ("rió", "rio", ReformKind::HiatusMonosyllable),
+        ]
+    );
+    for hint in hints {
+        assert_eq!(&text[hint.offset..hint.offset + hint.word.len()], hint.word);
+    }
+    assert!(check_reform("Solo leí este guion, 2 o 3 veces.").is_empty());
+}
+
+#[test]
+fn hiatus_tildes_are_current() {
+    assert!(check_reform("Está en el río").is_empty());
+    assert!(check_reform("Estás con frío; el lío del crío que guío. Que esté allí.").is_empty());
+    for orthography in [Orthography::Rae1999, Orthography::Rae2010] {
+        for word in ["río", "frío", "lío", "crío", "guío"] {
+            let check = Word::from(word).check_tilde_in(None, orthography).unwrap();
+            assert_eq!(check.tilde, Tilde::Required, "{}", word);
+            assert_eq!(check.error, None, "{}", word);
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use syllabize_es::Word;
+use syllabize_es::{Dialect, SyllabizeOptions, Word};
 
 #[test]
 fn agudo() {
@@ -41,3 +41,14 @@ fn esdrújulo_2() {
     let word: Word = "básico".into();
     assert!(!word.rhymes_with(&Word::from("ático"), None));
 }
+
+#[test]
+fn dialectos() {
+    let peninsular = SyllabizeOptions::default().with_dialect(Dialect::Peninsular);
+    let spanish = Word::parse_with_options("chipotle", peninsular).unwrap();
+    let mexican = Word::from("chipotle");
+    assert_eq!(spanish.rhyme(), mexican.rhyme());
+    assert!(spanish.rhymes_with(&mexican, None));
+    assert!(mexican.rhymes_with(&spanish, None));
+    assert!(spanish.assonant_rhymes_with(&Word::from("cofre")));
+}
@@ -0,0 +1,77 @@
+use syllabize_es::rules::{RulesError, SyllabizeRules};
+use syllabize_es::{Dialect, Word};
+
+const RULES: &str = "
+# Nahuatl and Mapudungun toponyms
+cluster tz
+cluster tx
+# Loanwords
+word Wind-sor
+word Fa-ce-book
+";
+
+fn split(word: &str, rules: &SyllabizeRules) -> String {
+    Word::parse_with(word, rules).unwrap().syllabize("-")
+}
+
+#[test]
+fn clusters() {
+    let rules = RULES.parse::<SyllabizeRules>().unwrap();
+    for (word, syllables) in [
+        ("Quetzalcóatl", "Que-tzal-có-atl"),
+        ("Coatzacoalcos", "Co-a-tza-co-al-cos"),
+        ("Pitxaqui", "Pi-txa-qui"),
+        ("atleta", "a-tle-ta"),
+        ("construir", "cons-truir"),
+        ("casa", "ca-sa"),
+    ] {
+        assert_eq!(split(word, &rules), syllables);
+    }
+    assert_eq!(
+        split("Quetzalcóatl", &SyllabizeRules::default()),
+        "Quet-zal-có-atl"
+    );
+}
+
+#[test]
+fn separate() {
+    let rules = "separate tl\nseparate bl"
+        .parse::<SyllabizeRules>()
+        .unwrap();
+    assert_eq!(split("atleta", &rules), "at-le-ta");
+    assert_eq!(split("Tlalpan", &rules), "Tlal-pan");
+    assert_eq!(split("hablar", &rules), "hab-lar");
+    assert_eq!(split("hablar", &SyllabizeRules::default()), "ha-blar");
+}
+
+#[test]
+fn fixed_words() {
+    let rules = RULES.parse::<SyllabizeRules>().unwrap();
+    assert_eq!(split("Windsor", &rules), "Wind-sor");
+    assert_eq!(split("WINDSOR", &rules), "WIND-SOR");
+    assert_eq!(split("facebook", &rules), "fa-ce-book");
+    let word = Word::parse_with("Facebook", &rules).unwrap();
+    assert_eq!(word.syllables.len(), 3);
+    assert_eq!(word.spans[2].syllable.bytes, 4..8);
+    assert_eq!(Word::from("Windsor").syllabize("-"), "Win-dsor");
+}
+
+#[test]
+fn options() {
+    let mut rules = SyllabizeRules::default();
+    rules.options.dialect = Dialect::Peninsular;
+    let word = Word::parse_with("atleta", &rules).unwrap();
+    assert_eq!(word.syllabize("-"), "at-le-ta");
+    assert_eq!(word.dialect, Dialect::Peninsular);
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        Word::parse_with("nn", &SyllabizeRules::default()).unwrap_err(),
+        Word::parse_with_options("nn", Default::default()).unwrap_err()
+    );
+    let err = "cluster tz\nword Wind-s-or".parse::<SyllabizeRules>();
+    assert_eq!(err, Err(RulesError::InvalidWord { line: 2 }));
+    assert_eq!(err.unwrap_err().to_string(), "invalid word split on line 2");
+}
@@ -0,0 +1,41 @@
+use syllabize_es::Word;
+
+#[test]
+fn syllable_spans() {
+    let s = "canción";
+    let w: Word = s.into();
+    let slices = w
+        .spans
+        .iter()
+        .map(|span| &s[span.syllable.bytes.clone()])
+        .collect::<Vec<&str>>();
+    assert_eq!(slices, vec!["can", "ción"]);
+    assert_eq!(w.spans[1].onset.chars, 3..4);
+    assert_eq!(w.spans[1].nucleus.chars, 4..6);
+    assert_eq!(w.spans[1].nucleus.bytes, 4..7);
+    assert_eq!(w.spans[1].coda.chars, 6..7);
+}
+
+#[test]
+fn decomposed_spans() {
+    let s = "cancio\u{301}n";
+    let w: Word = s.into();
+    assert_eq!(w.spans[1].syllable.bytes, 3..s.len());
+    assert_eq!(w.spans[1].nucleus.chars, 4..7);
+    assert_eq!(&s[w.spans[1].nucleus.bytes.clone()], "io\u{301}");
+}
+
+#[test]
+fn vowel_combo_spans() {
+    let s = "azahar";
+    let combos = Word::from(s).vowel_combos();
+    assert_eq!(&s[combos.hiatuses[0].span.bytes.clone()], "aha");
+
+    let s = "Paraguay";
+    let combos = Word::from(s).vowel_combos();
+    assert_eq!(combos.triphthongs[0].span.chars, 5..8);
+
+    let s = "peine";
+    let combos = Word::from(s).vowel_combos();
+    assert_eq!(&s[combos.diphthongs[0].span.bytes.clone()], "ei");
+}
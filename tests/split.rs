@@ -1,4 +1,4 @@
-use syllabize_es::Word;
+use syllabize_es::{Dialect, SplitMode, SyllabizeOptions, Word};
 // use regex::Regex;
 
 #[test]
@@ -113,7 +113,7 @@ fn triptongos() {
 
 #[test]
 fn no_triptongos() {
-    let data = vec!["lim-pia-ú-ñas", "vi-ví-ais"];
+    let data = ["lim-pia-ú-ñas", "vi-ví-ais"];
     let src = data
         .iter()
         .map(|s| (s.replace("-", ""), s.to_string()))
@@ -176,11 +176,10 @@ fn ad_hoc() {
 
 #[test]
 fn with_h() {
-    let data = vec![
+    let data = [
         "a-ni-hi-lar",
         "ma-ri-hua-na",
-        // TODO: fix failing case
-        // "vih", // not a word but oh well
+        "vih", // not a word but oh well
         "ahi", // wrong spelling but oh well
         "a-hí",
     ];
@@ -193,3 +192,112 @@ fn with_h() {
         assert_eq!(w.syllabize("-"), d);
     }
 }
+
+#[test]
+fn case_insensitive() {
+    let data = [
+        "que-so",
+        "gue-rra",
+        "hoy",
+        "ya-te",
+        "guí-a",
+        "qué",
+        "gua-po",
+        "a-hí",
+        "pin-güi-no",
+        "ca-rre-te-ra",
+        "chi-i-ta",
+    ];
+    for d in data {
+        let s = d.replace("-", "");
+        let mut chars = s.chars();
+        let capitalized = chars.next().unwrap().to_uppercase().collect::<String>() + chars.as_str();
+        let w: Word = s.to_uppercase().as_str().into();
+        assert_eq!(w.syllabize("-"), d.to_uppercase());
+        let w: Word = capitalized.as_str().into();
+        assert_eq!(w.syllabize("-").to_lowercase(), d);
+        assert_eq!(w.to_string(), capitalized);
+    }
+}
+
+#[test]
+fn dialectos() {
+    let peninsular = SyllabizeOptions::default().with_dialect(Dialect::Peninsular);
+    for (s, american, spanish) in [
+        ("atleta", "a-tle-ta", "at-le-ta"),
+        ("atlántico", "a-tlán-ti-co", "at-lán-ti-co"),
+        ("Tlalpan", "Tlal-pan", "Tlal-pan"),
+        ("náhuatl", "ná-huatl", "ná-huatl"),
+        ("Mazatlán", "Ma-za-tlán", "Ma-zat-lán"),
+        ("ATLETA", "A-TLE-TA", "AT-LE-TA"),
+    ] {
+        assert_eq!(Word::from(s).syllabize("-"), american);
+        let word = Word::parse_with_options(s, peninsular).unwrap();
+        assert_eq!(word.syllabize("-"), spanish);
+        assert_eq!(word.dialect, Dialect::Peninsular);
+        assert_eq!(word.with_stress(0).unwrap().dialect, Dialect::Peninsular);
+    }
+}
+
+#[test]
+fn prefijos() {
+    let morphological = SyllabizeOptions::default().with_mode(SplitMode::Morphological);
+    for (s, phonological, morphological_split) in [
+        ("subrayar", "su-bra-yar", "sub-ra-yar"),
+        ("desamor", "de-sa-mor", "des-a-mor"),
+        ("desechar", "de-se-char", "des-e-char"),
+        ("desesperar", "de-ses-pe-rar", "des-es-pe-rar"),
+        ("inútil", "i-nú-til", "in-ú-til"),
+        ("enamorar", "e-na-mo-rar", "en-a-mo-rar"),
+        (
+            "interamericano",
+            "in-te-ra-me-ri-ca-no",
+            "in-ter-a-me-ri-ca-no",
+        ),
+        (
+            "superabundante",
+            "su-pe-ra-bun-dan-te",
+            "su-per-a-bun-dan-te",
+        ),
+    ] {
+        assert_eq!(Word::from(s).syllabize("-"), phonological);
+        let word = Word::parse_with_options(s, morphological).unwrap();
+        assert_eq!(word.syllabize("-"), morphological_split, "{}", s);
+    }
+    for s in [
+        "e-ne-mi-go",
+        "a-bue-lo",
+        "a-bo-ga-do",
+        "a-bier-to",
+        "o-bis-po",
+        "o-be-de-cer",
+        "a-di-vi-nar",
+        "a-du-lar",
+        "pos-te-rior",
+        "e-nig-ma",
+        "su-pe-rá-vit",
+        "de-se-ar",
+        "de-sier-to",
+    ] {
+        let word = Word::parse_with_options(&s.replace('-', ""), morphological).unwrap();
+        assert_eq!(word.syllabize("-"), s);
+    }
+}
+
+#[test]
+fn guiones() {
+    let peninsular = SyllabizeOptions::default().with_dialect(Dialect::Peninsular);
+    for (s, american, spanish) in [
+        ("atleta", vec![4], vec![2, 4]),
+        ("atlántico", vec![5, 7], vec![2, 5, 7]),
+        ("creer", vec![], vec![]),
+        ("aéreo", vec![2], vec![2]),
+        ("ahora", vec![3], vec![3]),
+        ("caballo", vec![2, 4], vec![2, 4]),
+        ("Tlalpan", vec![4], vec![4]),
+    ] {
+        assert_eq!(Word::from(s).hyphenation_points(), american, "{}", s);
+        let word = Word::parse_with_options(s, peninsular).unwrap();
+        assert_eq!(word.hyphenation_points(), spanish, "{}", s);
+    }
+}
@@ -4,10 +4,11 @@ use syllabize_es::{StressType, Word};
 fn test_oxytone() {
     let data = vec![
         "a", "la", "gol", "olé", "pié", "piedad", "pastel", "habló", "reloj", "vivir", "Paraguay",
+        "estoy", "virrey",
     ];
     for s in data {
         let w: Word = s.into();
-        assert_eq!(w.stress(), StressType::Oxytone);
+        assert_eq!(w.stress(), Some(StressType::Oxytone));
     }
 }
 
@@ -22,10 +23,12 @@ fn test_paroxytone() {
         "espantoso",
         "bíceps",
         "fértil",
+        "CANCIONES",
+        "Examen",
     ];
     for s in data {
         let w: Word = s.into();
-        assert_eq!(w.stress(), StressType::Paroxytone);
+        assert_eq!(w.stress(), Some(StressType::Paroxytone));
     }
 }
 
@@ -34,7 +37,7 @@ fn test_proparoxytones() {
     let data = vec!["esdrújula", "teléfono", "árboles"];
     for s in data {
         let w: Word = s.into();
-        assert_eq!(w.stress(), StressType::Proparoxytone);
+        assert_eq!(w.stress(), Some(StressType::Proparoxytone));
     }
 }
 
@@ -43,6 +46,59 @@ fn test_superproparoxytone() {
     let data = vec!["tráiganosla", "gíratelo", "rápidamente", "ávidamente"];
     for s in data {
         let w: Word = s.into();
-        assert_eq!(w.stress(), StressType::Superproparoxytone);
+        assert_eq!(w.stress(), Some(StressType::Superproparoxytone));
     }
 }
+
+#[test]
+fn test_strip_accents() {
+    let data = [
+        "término",
+        "termino",
+        "terminó",
+        "continúo",
+        "continuo",
+        "continuó",
+        "huía",
+        "Ángel",
+        "curry",
+        "whisky",
+    ];
+    let mut bare = vec![];
+    for s in data {
+        let stripped = Word::from(s).strip_accents().unwrap();
+        assert_eq!(stripped.restore().unwrap(), s);
+        bare.push(stripped);
+    }
+    assert_eq!(bare[0].bare, bare[1].bare);
+    assert_ne!(bare[0].stress_index, bare[1].stress_index);
+    assert_eq!(bare[7].bare, "Angel");
+}
+
+#[test]
+fn test_secondary_stress() {
+    let data: &[(&str, usize, &[usize])] = &[
+        ("rápidamente", 3, &[0]),
+        ("ávidamente", 3, &[0]),
+        ("fácilmente", 2, &[0]),
+        ("comúnmente", 2, &[1]),
+        ("buenamente", 2, &[0]),
+        ("felizmente", 2, &[1]),
+        ("teórico-práctico", 4, &[1]),
+        ("franco-alemán", 4, &[0]),
+        ("casa", 0, &[]),
+        ("demente", 1, &[]),
+        ("clemente", 1, &[]),
+        ("mente", 0, &[]),
+    ];
+    for (s, primary, secondary) in data {
+        let stresses = Word::from(*s).stresses();
+        assert_eq!(stresses.primary, *primary, "{}", s);
+        assert_eq!(stresses.secondary, *secondary, "{}", s);
+    }
+    // The spelling still puts the stress on the adjective.
+    assert_eq!(
+        Word::from("rápidamente").stress(),
+        Some(StressType::Superproparoxytone)
+    );
+}